@@ -0,0 +1,107 @@
+use embassy_stm32::i2c::{Error as I2cError, I2c};
+use embassy_time::Delay;
+use embedded_hal_1::delay::DelayNs;
+use embedded_hal_1::i2c::{ErrorType, I2c as EmbeddedHalI2c, Operation};
+
+/// Longest write or read the wrapper can merge into one I2C transfer
+///
+/// Covers the 2 dummy bytes plus a 20-byte `read_all` burst; raise it to
+/// drain the FIFO in larger chunks.
+const MAX_TRANSFER: usize = 32;
+
+pub struct EmbassyI2cWrapper<'d, T: embassy_stm32::i2c::Instance, TXDMA, RXDMA>(
+    pub I2c<'d, T, TXDMA, RXDMA>,
+);
+
+impl<'d, T: embassy_stm32::i2c::Instance, TXDMA, RXDMA> EmbeddedHalI2c
+    for EmbassyI2cWrapper<'d, T, TXDMA, RXDMA>
+{
+    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.blocking_read(address, buffer)
+    }
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.blocking_write(address, bytes)
+    }
+
+    fn write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.0.blocking_write_read(address, bytes, buffer)
+    }
+
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        // embassy-stm32 0.1 has no I2C transaction support. The BMI323 needs
+        // the register address, dummy bytes and data in one transfer, so merge
+        // the writes and the reads into a single write or write-read instead
+        // of issuing one STOP-terminated transfer per operation.
+        let mut write = [0u8; MAX_TRANSFER];
+        let mut write_len = 0;
+        let mut read_len = 0;
+        for op in operations.iter() {
+            match op {
+                Operation::Write(bytes) => {
+                    if read_len > 0 {
+                        // A write after a read needs a repeated start
+                        return Err(I2cError::Bus);
+                    }
+                    let end = write_len + bytes.len();
+                    write
+                        .get_mut(write_len..end)
+                        .ok_or(I2cError::Overrun)?
+                        .copy_from_slice(bytes);
+                    write_len = end;
+                }
+                Operation::Read(buffer) => read_len += buffer.len(),
+            }
+        }
+
+        if read_len == 0 {
+            return self.0.blocking_write(address, &write[..write_len]);
+        }
+        let mut read = [0u8; MAX_TRANSFER];
+        let read = read.get_mut(..read_len).ok_or(I2cError::Overrun)?;
+        self.0
+            .blocking_write_read(address, &write[..write_len], read)?;
+
+        // Hand the received bytes, dummy bytes included, back to the buffers
+        let mut received = &read[..];
+        for op in operations {
+            if let Operation::Read(buffer) = op {
+                let (chunk, rest) = received.split_at(buffer.len());
+                buffer.copy_from_slice(chunk);
+                received = rest;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'d, T: embassy_stm32::i2c::Instance, TXDMA, RXDMA> ErrorType
+    for EmbassyI2cWrapper<'d, T, TXDMA, RXDMA>
+{
+    type Error = I2cError;
+}
+
+pub struct EmbassyDelayWrapper;
+
+impl DelayNs for EmbassyDelayWrapper {
+    fn delay_ns(&mut self, ns: u32) {
+        Delay.delay_ns(ns);
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        Delay.delay_us(us);
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        Delay.delay_ms(ms);
+    }
+}
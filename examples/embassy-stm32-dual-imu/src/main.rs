@@ -0,0 +1,90 @@
+#![no_std]
+#![no_main]
+
+use core::cell::RefCell;
+
+use defmt::*;
+use embassy_executor::Spawner;
+use embassy_stm32::i2c::{Config, I2c};
+use embassy_stm32::time::Hertz;
+use embassy_stm32::{bind_interrupts, i2c, peripherals};
+use embedded_hal_bus::i2c::RefCellDevice;
+use {defmt_rtt as _, panic_probe as _};
+
+mod hal_compat;
+use hal_compat::{EmbassyDelayWrapper, EmbassyI2cWrapper};
+
+use bmi323::{AccelConfig, Bmi323, GyroConfig, SlaveAddress};
+
+bind_interrupts!(struct Irqs {
+    I2C1_EV => i2c::EventInterruptHandler<peripherals::I2C1>;
+    I2C1_ER => i2c::ErrorInterruptHandler<peripherals::I2C1>;
+});
+
+#[embassy_executor::main]
+async fn main(_spawner: Spawner) {
+    let p = embassy_stm32::init(Default::default());
+    let mut i2c_config = Config::default();
+    i2c_config.scl_pullup = true;
+    i2c_config.sda_pullup = true;
+    let i2c = I2c::new(
+        p.I2C1,
+        p.PB6,
+        p.PB7,
+        Irqs,
+        p.GPDMA1_CH4,
+        p.GPDMA1_CH5,
+        Hertz(400_000),
+        i2c_config,
+    );
+
+    // Both IMUs sit on the same bus, one with SDO low (0x68) and one with SDO high (0x69)
+    let i2c_bus = RefCell::new(EmbassyI2cWrapper(i2c));
+
+    let found = match bmi323::scan_i2c(&mut RefCellDevice::new(&i2c_bus)) {
+        Ok(found) => found,
+        Err(e) => {
+            info!("I2C scan failed: {:?}", Debug2Format(&e));
+            return;
+        }
+    };
+    if found != [Some(SlaveAddress::SdoLow), Some(SlaveAddress::SdoHigh)] {
+        info!("Expected two BMI323s, found {:?}", Debug2Format(&found));
+        return;
+    }
+
+    let mut imu_a = Bmi323::new_with_i2c(
+        RefCellDevice::new(&i2c_bus),
+        SlaveAddress::SdoLow,
+        EmbassyDelayWrapper,
+    );
+    let mut imu_b = Bmi323::new_with_i2c(
+        RefCellDevice::new(&i2c_bus),
+        SlaveAddress::SdoHigh,
+        EmbassyDelayWrapper,
+    );
+
+    for imu in [&mut imu_a, &mut imu_b] {
+        if let Err(e) = imu.init() {
+            info!("BMI323 initialization error: {:?}", Debug2Format(&e));
+        }
+        if let Err(e) = imu.set_accel_config(AccelConfig::builder().build()) {
+            info!("Failed to configure accelerometer: {:?}", Debug2Format(&e));
+        }
+        if let Err(e) = imu.set_gyro_config(GyroConfig::builder().build()) {
+            info!("Failed to configure gyroscope: {:?}", Debug2Format(&e));
+        }
+    }
+
+    loop {
+        match (imu_a.read_all_scaled(), imu_b.read_all_scaled()) {
+            (Ok(a), Ok(b)) => {
+                info!(
+                    "IMU A accel: x={}, y={}, z={} | IMU B accel: x={}, y={}, z={}",
+                    a.accel.x, a.accel.y, a.accel.z, b.accel.x, b.accel.y, b.accel.z
+                );
+            }
+            (Err(e), _) | (_, Err(e)) => info!("Failed to read IMU data: {:?}", Debug2Format(&e)),
+        }
+    }
+}
@@ -47,20 +47,60 @@ impl ErrorType for I2C1 {
     type Error = I2cError;
 }
 
+/// Longest write or read `transaction` can merge into one I2C transfer
+///
+/// Covers the 2 dummy bytes plus a 20-byte `read_all` burst; raise it to
+/// drain the FIFO in larger chunks.
+const MAX_TRANSFER: usize = 32;
+
 impl embedded_hal::i2c::I2c<SevenBitAddress> for I2C1 {
     fn transaction(
         &mut self,
         address: SevenBitAddress,
         operations: &mut [Operation<'_>],
     ) -> Result<(), Self::Error> {
-        for op in operations {
+        // The BMI323 needs the register address, dummy bytes and data in one
+        // transfer, so merge the writes and the reads into a single write or
+        // write-read with a repeated start instead of issuing one
+        // STOP-terminated transfer per operation.
+        let mut write = [0u8; MAX_TRANSFER];
+        let mut write_len = 0;
+        let mut read_len = 0;
+        for op in operations.iter() {
             match op {
-                Operation::Read(buffer) => {
-                    self.read(address, buffer)?;
-                }
-                Operation::Write(buffer) => {
-                    self.write(address, buffer)?;
+                Operation::Write(bytes) => {
+                    if read_len > 0 {
+                        // A write after a read needs another repeated start
+                        return Err(I2cError::BusError);
+                    }
+                    let end = write_len + bytes.len();
+                    write
+                        .get_mut(write_len..end)
+                        .ok_or(I2cError::OverrunError)?
+                        .copy_from_slice(bytes);
+                    write_len = end;
                 }
+                Operation::Read(buffer) => read_len += buffer.len(),
+            }
+        }
+
+        if read_len == 0 {
+            return self.write(address, &write[..write_len], true);
+        }
+        let mut read = [0u8; MAX_TRANSFER];
+        let read = read.get_mut(..read_len).ok_or(I2cError::OverrunError)?;
+        if write_len > 0 {
+            self.write(address, &write[..write_len], false)?;
+        }
+        self.read(address, read)?;
+
+        // Hand the received bytes, dummy bytes included, back to the buffers
+        let mut received = &read[..];
+        for op in operations {
+            if let Operation::Read(buffer) = op {
+                let (chunk, rest) = received.split_at(buffer.len());
+                buffer.copy_from_slice(chunk);
+                received = rest;
             }
         }
         Ok(())
@@ -183,7 +223,11 @@ impl I2C1 {
     }
 
 
-    fn write(&mut self, address: u8, buffer: &[u8]) -> Result<(), I2cError> {
+    /// Write `buffer`, ending with a STOP if `stop` is set
+    ///
+    /// Without a STOP the bus is held after the last byte, so the next
+    /// `read` starts with a repeated start.
+    fn write(&mut self, address: u8, buffer: &[u8], stop: bool) -> Result<(), I2cError> {
         let i2c = &self.i2c;
 
         // Configuring the I2C peripheral: START condition, device address, length, and AUTOEND
@@ -191,7 +235,7 @@ impl I2C1 {
             w.sadd().bits((address << 1) as u16)
              .nbytes().bits(buffer.len() as u8)
              .start().set_bit()
-             .autoend().set_bit()
+             .autoend().bit(stop)
         });
 
         // Write bytes from the buffer to the transmit data register
@@ -202,10 +246,15 @@ impl I2C1 {
             i2c.txdr().write(|w| unsafe { w.txdata().bits(byte) });
         }
 
-        // Wait until STOP flag is set
-        while i2c.isr().read().stopf().bit_is_clear() {}
-        // Clear the STOP flag
-        i2c.icr().write(|w| w.stopcf().set_bit());
+        if stop {
+            // Wait until STOP flag is set
+            while i2c.isr().read().stopf().bit_is_clear() {}
+            // Clear the STOP flag
+            i2c.icr().write(|w| w.stopcf().set_bit());
+        } else {
+            // Wait until the transfer is complete, with SCL held low
+            while i2c.isr().read().tc().bit_is_clear() {}
+        }
 
         // Check for NACK
         if i2c.isr().read().nackf().bit_is_set() {
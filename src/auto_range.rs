@@ -0,0 +1,114 @@
+use crate::types::{AccelerometerRange, GyroscopeRange, Sensor3DData};
+
+/// Settings for automatic range switching
+///
+/// A sensor is switched to the next larger range once its saturation flag has
+/// been set for `saturation_count` consecutive reads, and to the next smaller
+/// range once all axes stayed below `low_percent` of full scale for
+/// `low_count` consecutive reads. The read that triggers a switch still
+/// returns its sample; the new range is written at the start of the next
+/// read of that sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoRangeConfig {
+    /// Consecutive saturated reads before switching to a larger range
+    pub saturation_count: u16,
+    /// Fraction of full scale, in percent, below which a read counts as low
+    pub low_percent: u8,
+    /// Consecutive low reads before switching to a smaller range
+    pub low_count: u16,
+}
+
+impl Default for AutoRangeConfig {
+    fn default() -> Self {
+        AutoRangeConfig {
+            saturation_count: 3,
+            low_percent: 25,
+            low_count: 100,
+        }
+    }
+}
+
+/// Range change requested by the auto-ranging logic
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RangeStep {
+    /// Keep the current range
+    Keep,
+    /// Switch to the next larger range
+    Up,
+    /// Switch to the next smaller range
+    Down,
+}
+
+/// Consecutive read counters for one sensor
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RangeCounter {
+    saturated: u16,
+    low: u16,
+}
+
+impl RangeCounter {
+    /// Account for a new read and decide whether the range should change
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The auto-ranging settings
+    /// * `saturated` - Whether any axis of the sensor reported saturation
+    /// * `data` - The raw data of the read
+    pub(crate) fn update(
+        &mut self,
+        config: &AutoRangeConfig,
+        saturated: bool,
+        data: Sensor3DData,
+    ) -> RangeStep {
+        if saturated {
+            self.low = 0;
+            self.saturated = self.saturated.saturating_add(1);
+            if self.saturated >= config.saturation_count {
+                *self = Self::default();
+                return RangeStep::Up;
+            }
+            return RangeStep::Keep;
+        }
+
+        self.saturated = 0;
+        let peak = [data.x, data.y, data.z]
+            .iter()
+            .map(|v| i32::from(*v).abs())
+            .max()
+            .unwrap_or(0);
+        if peak * 100 < i32::from(config.low_percent) * 32768 {
+            self.low = self.low.saturating_add(1);
+            if self.low >= config.low_count {
+                *self = Self::default();
+                return RangeStep::Down;
+            }
+        } else {
+            self.low = 0;
+        }
+        RangeStep::Keep
+    }
+}
+
+/// Auto-ranging state kept by the driver
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AutoRange {
+    pub(crate) config: AutoRangeConfig,
+    pub(crate) accel: RangeCounter,
+    pub(crate) gyro: RangeCounter,
+    /// Accelerometer range to switch to before the next read
+    pub(crate) pending_accel: Option<AccelerometerRange>,
+    /// Gyroscope range to switch to before the next read
+    pub(crate) pending_gyro: Option<GyroscopeRange>,
+}
+
+impl AutoRange {
+    pub(crate) fn new(config: AutoRangeConfig) -> Self {
+        AutoRange {
+            config,
+            accel: RangeCounter::default(),
+            gyro: RangeCounter::default(),
+            pending_accel: None,
+            pending_gyro: None,
+        }
+    }
+}
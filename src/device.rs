@@ -1,9 +1,80 @@
 use crate::{
+    auto_range::{AutoRange, AutoRangeConfig, RangeStep},
     interface::{I2cInterface, ReadData, SpiInterface, WriteData},
-    types::{AccelerometerRange, GyroscopeRange, Sensor3DData, Sensor3DDataScaled, SensorType},
-    AccelConfig, Bmi323, Error, GyroConfig, Register,
+    types::{
+        AccelUnit, AccelerometerPowerMode, AccelerometerRange, ConfigError, DeviceErrors,
+        DriveStrength, FifoConfig, GyroUnit, GyroscopePowerMode, GyroscopeRange, I2cWatchdog,
+        ImuData, ImuDataScaled, InterruptPin, InterruptSource, SaturationFlags, Sensor3DData,
+        Sensor3DDataScaled, SensorType, SlaveAddress, SpiMode,
+    },
+    AccelConfig, Bmi323, Error, GyroConfig, Register, GRAVITY,
 };
-use embedded_hal::delay::DelayNs;
+use embedded_hal::{
+    delay::DelayNs,
+    i2c::{Error as _, ErrorKind, I2c},
+};
+#[cfg(feature = "async")]
+use embedded_hal_async::digital::Wait;
+
+/// Accelerometer data ready bit of the STATUS low byte
+const DRDY_ACC: u8 = 0b1000_0000;
+/// Gyroscope data ready bit of the STATUS low byte
+const DRDY_GYR: u8 = 0b0100_0000;
+/// Default of [`Bmi323::set_config_wait`], also bounding the wait after a
+/// range switch when the configuration wait is disabled
+const DEFAULT_CONFIG_WAIT_MS: u32 = 100;
+
+/// Data ready bit of `sensor_type` in the STATUS low byte
+fn drdy_mask(sensor_type: SensorType) -> u8 {
+    match sensor_type {
+        SensorType::Accelerometer => DRDY_ACC,
+        SensorType::Gyroscope => DRDY_GYR,
+    }
+}
+
+/// Check whether a BMI323 answers at the given I2C address
+///
+/// A missing acknowledge is reported as `Ok(false)`, other bus errors are
+/// returned as `Error::Comm`.
+///
+/// # Arguments
+///
+/// * `i2c` - The I2C bus
+/// * `address` - The address to probe
+pub fn probe_i2c<I2C>(i2c: &mut I2C, address: SlaveAddress) -> Result<bool, Error<I2C::Error>>
+where
+    I2C: I2c,
+{
+    let mut data = [0u8; 3]; // 2 dummy bytes followed by the chip ID
+    match i2c.write_read(address.into(), &[Register::CHIPID], &mut data) {
+        Ok(()) => Ok(data[2] == Register::BMI323_CHIP_ID),
+        Err(e) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => Ok(false),
+        Err(e) => Err(Error::Comm(e)),
+    }
+}
+
+/// Probe both BMI323 I2C addresses
+///
+/// Returns the addresses a BMI323 answered at, in `[SdoLow, SdoHigh]` order.
+///
+/// # Arguments
+///
+/// * `i2c` - The I2C bus
+pub fn scan_i2c<I2C>(i2c: &mut I2C) -> Result<[Option<SlaveAddress>; 2], Error<I2C::Error>>
+where
+    I2C: I2c,
+{
+    let mut found = [None; 2];
+    for (slot, address) in found
+        .iter_mut()
+        .zip([SlaveAddress::SdoLow, SlaveAddress::SdoHigh])
+    {
+        if probe_i2c(i2c, address)? {
+            *slot = Some(address);
+        }
+    }
+    Ok(found)
+}
 
 impl<I2C, D> Bmi323<I2cInterface<I2C>, D>
 where
@@ -13,15 +84,16 @@ where
     ///
     /// # Arguments
     ///
-    /// * `iface` - The communication interface
+    /// * `i2c` - The I2C bus or device
+    /// * `address` - The I2C address, either a [`SlaveAddress`] or a raw `u8`
     /// * `delay` - A delay provider
-    pub fn new_with_i2c(i2c: I2C, address: u8, delay: D) -> Self {
-        Bmi323 {
-            iface: I2cInterface { i2c, address },
-            delay,
-            accel_range: AccelerometerRange::default(),
-            gyro_range: GyroscopeRange::default(),
-        }
+    pub fn new_with_i2c(i2c: I2C, address: impl Into<u8>, delay: D) -> Self {
+        Self::new(I2cInterface::new(i2c, address), delay)
+    }
+
+    /// Destroy the driver instance and return the I2C bus and delay
+    pub fn destroy(self) -> (I2C, D) {
+        (self.iface.i2c, self.delay)
     }
 }
 
@@ -36,30 +108,92 @@ where
     /// * `iface` - The communication interface
     /// * `delay` - A delay provider
     pub fn new_with_spi(spi: SPI, delay: D) -> Self {
+        Self::new_with_spi_mode(spi, SpiMode::FourWire, delay)
+    }
+
+    /// Create a new BMI323 device instance using SPI in the given wiring mode
+    ///
+    /// In 3-wire mode `init` writes IO_SPI_IF after the switch to SPI, so
+    /// `init` must be called before any read.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi` - The SPI device
+    /// * `mode` - 3-wire or 4-wire SPI
+    /// * `delay` - A delay provider
+    pub fn new_with_spi_mode(spi: SPI, mode: SpiMode, delay: D) -> Self {
+        Self::new(SpiInterface::new(spi, mode), delay)
+    }
+
+    /// Destroy the driver instance and return the SPI device and delay
+    pub fn destroy(self) -> (SPI, D) {
+        (self.iface.spi, self.delay)
+    }
+}
+
+impl<DI, D> Bmi323<DI, D> {
+    /// Create a new BMI323 device instance on any interface
+    ///
+    /// Use this with a custom transport implementing
+    /// [`ReadData`] and [`WriteData`].
+    ///
+    /// # Arguments
+    ///
+    /// * `iface` - The communication interface
+    /// * `delay` - A delay provider
+    pub fn new(iface: DI, delay: D) -> Self {
         Bmi323 {
-            iface: SpiInterface { spi },
+            iface,
             delay,
             accel_range: AccelerometerRange::default(),
             gyro_range: GyroscopeRange::default(),
+            accel_config: None,
+            gyro_config: None,
+            accel_odr: None,
+            gyro_odr: None,
+            auto_range: None,
+            feature_engine_suspended: false,
+            config_wait_ms: Some(DEFAULT_CONFIG_WAIT_MS),
+            accel_unit: AccelUnit::default(),
+            gyro_unit: GyroUnit::default(),
+            gravity: GRAVITY,
         }
     }
+
+    /// Destroy the driver instance and return the interface and delay
+    pub fn into_parts(self) -> (DI, D) {
+        (self.iface, self.delay)
+    }
+
+    /// Mutable access to the communication interface
+    ///
+    /// Intended for advanced use. Traffic sent this way bypasses the driver's
+    /// cached state, such as the current ranges.
+    pub fn iface_mut(&mut self) -> &mut DI {
+        &mut self.iface
+    }
 }
 
 impl<DI, D, E> Bmi323<DI, D>
 where
-    DI: ReadData<Error = Error<E>> + WriteData<Error = Error<E>>,
+    DI: ReadData<Error = E> + WriteData<Error = E>,
     D: DelayNs,
 {
     /// Initialize the device
+    ///
+    /// Performs a soft reset, checks the error register and verifies the chip
+    /// ID. Over SPI, a dummy read switches the device to SPI mode after
+    /// power-on and again after the reset.
     pub fn init(&mut self) -> Result<(), Error<E>> {
-        self.write_register_16bit(Register::CMD, Register::CMD_SOFT_RESET)?;
+        self.select_interface()?;
+        self.write_word(Register::CMD, Register::CMD_SOFT_RESET)?;
         self.delay.delay_us(2000);
+        self.reset_cached_state();
+        self.select_interface()?;
 
-        //let mut reg_data = [0u8; 3];
-        //reg_data[0] = 0x01; // sensor error conditins register
-        let status = self.read_register(0x01)?;
-        if (status & 0b0000_0001) != 0 {
-            return Err(Error::InvalidDevice);
+        let errors = self.read_errors()?;
+        if errors.fatal {
+            return Err(Error::Fatal(errors));
         }
 
         let result = self.read_register(Register::CHIPID)?;
@@ -70,19 +204,154 @@ where
         Ok(())
     }
 
+    /// Switch the device to SPI if the interface is SPI
+    fn select_interface(&mut self) -> Result<(), Error<E>> {
+        let Some(mode) = self.iface.spi_mode() else {
+            return Ok(());
+        };
+        // A rising edge on CSB switches the device to SPI, the read result is invalid
+        self.read_register(Register::CHIPID)?;
+        if mode == SpiMode::ThreeWire {
+            self.write_word(Register::IO_SPI_IF, SpiMode::ThreeWire as u16)?;
+        }
+        Ok(())
+    }
+
+    /// Forget the state the soft reset returned to its defaults
+    ///
+    /// The sensors are off at their default ranges, so there is nothing for
+    /// `resume` to restore and pending range switches are dropped.
+    fn reset_cached_state(&mut self) {
+        self.accel_range = AccelerometerRange::default();
+        self.gyro_range = GyroscopeRange::default();
+        self.accel_config = None;
+        self.gyro_config = None;
+        self.accel_odr = None;
+        self.gyro_odr = None;
+        self.feature_engine_suspended = false;
+        if let Some(auto_range) = self.auto_range.as_mut() {
+            *auto_range = AutoRange::new(auto_range.config);
+        }
+    }
+
+    /// Read and decode the error register
+    pub fn read_errors(&mut self) -> Result<DeviceErrors, Error<E>> {
+        let errors = self.read_word(Register::ERR_REG)?;
+        Ok(DeviceErrors::from(errors))
+    }
+
+    /// Read the error register and turn any error condition into an `Error`
+    ///
+    /// Fatal errors take precedence over feature engine errors, then
+    /// configuration errors and finally I3C errors. A rejected configuration
+    /// names the sensor; if both were rejected, the accelerometer is reported
+    /// and [`Self::read_errors`] shows both.
+    pub fn check_errors(&mut self) -> Result<(), Error<E>> {
+        let errors = self.read_errors()?;
+        if errors.fatal {
+            Err(Error::Fatal(errors))
+        } else if errors.feature_engine() {
+            Err(Error::FeatureEngine(errors))
+        } else if let Some(sensor) = errors.rejected_sensor() {
+            Err(Error::InvalidConfig(ConfigError::Rejected(sensor)))
+        } else if errors.i3c() {
+            Err(Error::I3c(errors))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Set how long configuration changes wait for the first sample
+    ///
+    /// After writing an enabled configuration, [`Self::set_accel_config`] and
+    /// [`Self::set_gyro_config`] poll the data ready flag every millisecond
+    /// and return [`Error::Timeout`] if it is not set within `timeout_ms`.
+    /// `None` returns right after the write; use the `try_read_*` methods to
+    /// wait for data without blocking. Defaults to 100 ms.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout_ms` - The maximum wait in milliseconds, or `None`
+    pub fn set_config_wait(&mut self, timeout_ms: Option<u32>) {
+        self.config_wait_ms = timeout_ms;
+    }
+
+    /// Set the units of the scaled reads
+    ///
+    /// Defaults to m/s² and degrees per second.
+    ///
+    /// # Arguments
+    ///
+    /// * `accel_unit` - The unit of scaled accelerometer data
+    /// * `gyro_unit` - The unit of scaled gyroscope data
+    pub fn set_output_units(&mut self, accel_unit: AccelUnit, gyro_unit: GyroUnit) {
+        self.accel_unit = accel_unit;
+        self.gyro_unit = gyro_unit;
+    }
+
+    /// Get the units of the scaled reads
+    pub fn output_units(&self) -> (AccelUnit, GyroUnit) {
+        (self.accel_unit, self.gyro_unit)
+    }
+
+    /// Set the gravity used for accelerometer data in m/s^2
+    ///
+    /// Defaults to the standard gravity [`GRAVITY`]. Set the local value
+    /// to get m/s^2 that match the gravity at the measurement site.
+    ///
+    /// # Arguments
+    ///
+    /// * `gravity` - The gravity in m/s^2
+    pub fn set_gravity(&mut self, gravity: f32) {
+        self.gravity = gravity;
+    }
+
+    /// Get the gravity used for accelerometer data in m/s^2
+    pub fn gravity(&self) -> f32 {
+        self.gravity
+    }
+
+    /// Scale a burst read with the current ranges, output units and gravity
+    pub(crate) fn scale_all(&self, data: ImuData) -> ImuDataScaled {
+        data.to_scaled_with_gravity(
+            self.accel_range,
+            self.gyro_range,
+            self.accel_unit,
+            self.gyro_unit,
+            self.gravity,
+        )
+    }
+
     /// Set the accelerometer configuration
     ///
     /// # Arguments
     ///
     /// * `config` - The accelerometer configuration
     pub fn set_accel_config(&mut self, config: AccelConfig) -> Result<(), Error<E>> {
-        let reg_data = self.config_to_reg_data(config);
-        self.write_register_16bit(Register::ACC_CONF, reg_data)?;
-        self.accel_range = config.range;
+        self.write_accel_config(config)?;
+
+        // Wait for accelerometer data to be ready, unless it was turned off
+        if config.is_enabled() {
+            self.wait_for_data_ready(SensorType::Accelerometer)?;
+        }
+
+        Ok(())
+    }
 
-        // Wait for accelerometer data to be ready
-        self.wait_for_data_ready(SensorType::Accelerometer)?;
+    /// Write the accelerometer configuration without waiting for data
+    fn write_accel_config(&mut self, config: AccelConfig) -> Result<(), Error<E>> {
+        config.validate().map_err(Error::InvalidConfig)?;
+        let reg_data = self.config_to_reg_data(config);
+        self.write_word(Register::ACC_CONF, reg_data)?;
 
+        if self.read_errors()?.acc_conf {
+            return Err(Error::InvalidConfig(ConfigError::Rejected(
+                SensorType::Accelerometer,
+            )));
+        }
+        self.accel_range = config.range;
+        self.accel_config = Some(config);
+        self.accel_odr = config.is_enabled().then_some(config.odr);
         Ok(())
     }
 
@@ -92,13 +361,122 @@ where
     ///
     /// * `config` - The gyroscope configuration
     pub fn set_gyro_config(&mut self, config: GyroConfig) -> Result<(), Error<E>> {
+        self.write_gyro_config(config)?;
+
+        // Wait for gyroscope data to be ready, unless it was turned off
+        if config.is_enabled() {
+            self.wait_for_data_ready(SensorType::Gyroscope)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the gyroscope configuration without waiting for data
+    fn write_gyro_config(&mut self, config: GyroConfig) -> Result<(), Error<E>> {
+        config.validate().map_err(Error::InvalidConfig)?;
         let reg_data = self.config_to_reg_data(config);
-        self.write_register_16bit(Register::GYR_CONF, reg_data)?;
+        self.write_word(Register::GYR_CONF, reg_data)?;
+
+        if self.read_errors()?.gyr_conf {
+            return Err(Error::InvalidConfig(ConfigError::Rejected(
+                SensorType::Gyroscope,
+            )));
+        }
         self.gyro_range = config.range;
+        self.gyro_config = Some(config);
+        self.gyro_odr = config.is_enabled().then_some(config.odr);
+        Ok(())
+    }
 
-        // Wait for gyroscope data to be ready
-        self.wait_for_data_ready(SensorType::Gyroscope)?;
+    /// Read back the accelerometer configuration the device is running
+    ///
+    /// The cached range used for scaling is updated to match. The
+    /// configuration restored by [`Self::resume`] is not, so reading back a
+    /// suspended sensor does not turn it off for good.
+    pub fn get_accel_config(&mut self) -> Result<AccelConfig, Error<E>> {
+        let reg_data = self.read_word(Register::ACC_CONF)?;
+        let config = AccelConfig::try_from(reg_data).map_err(Error::InvalidConfig)?;
+        self.accel_range = config.range;
+        self.accel_odr = config.is_enabled().then_some(config.odr);
+        Ok(config)
+    }
+
+    /// Read back the gyroscope configuration the device is running
+    ///
+    /// The cached range used for scaling is updated to match. The
+    /// configuration restored by [`Self::resume`] is not, so reading back a
+    /// suspended sensor does not turn it off for good.
+    pub fn get_gyro_config(&mut self) -> Result<GyroConfig, Error<E>> {
+        let reg_data = self.read_word(Register::GYR_CONF)?;
+        let config = GyroConfig::try_from(reg_data).map_err(Error::InvalidConfig)?;
+        self.gyro_range = config.range;
+        self.gyro_odr = config.is_enabled().then_some(config.odr);
+        Ok(config)
+    }
 
+    /// Turn both sensors off, keeping the last applied configurations
+    ///
+    /// Only the power mode fields of ACC_CONF and GYR_CONF are cleared, so
+    /// [`Self::resume`] can restore the configurations passed to
+    /// [`Self::set_accel_config`] and [`Self::set_gyro_config`].
+    ///
+    /// # Arguments
+    ///
+    /// * `feature_engine` - Also turn the feature engine off, if running
+    pub fn suspend(&mut self, feature_engine: bool) -> Result<(), Error<E>> {
+        self.set_power_mode(Register::ACC_CONF, AccelerometerPowerMode::Disable as u16)?;
+        self.set_power_mode(Register::GYR_CONF, GyroscopePowerMode::Disable as u16)?;
+
+        if feature_engine && self.read_word(Register::FEATURE_CTRL)? & 0x0001 != 0 {
+            self.write_word(Register::FEATURE_CTRL, 0x0000)?;
+            self.feature_engine_suspended = true;
+        }
+        Ok(())
+    }
+
+    /// Put the gyroscope drive into suspend mode
+    ///
+    /// The drive keeps running without producing data, so the gyroscope
+    /// starts up faster than from [`GyroscopePowerMode::Disable`]. Call
+    /// [`Self::resume`] to restore the last applied configuration.
+    pub fn suspend_gyro(&mut self) -> Result<(), Error<E>> {
+        self.set_power_mode(Register::GYR_CONF, GyroscopePowerMode::Suspend as u16)
+    }
+
+    /// Put the device into its lowest-current state
+    ///
+    /// Both sensors and the feature engine are turned off. Same as
+    /// `suspend(true)`, and undone by [`Self::resume`].
+    pub fn power_down(&mut self) -> Result<(), Error<E>> {
+        self.suspend(true)
+    }
+
+    /// Restore the state from before [`Self::suspend`] or [`Self::suspend_gyro`]
+    ///
+    /// Restarts the feature engine if `suspend` stopped it, then re-applies
+    /// the last configuration written to each sensor. Sensors that were
+    /// never configured stay off.
+    pub fn resume(&mut self) -> Result<(), Error<E>> {
+        if self.feature_engine_suspended {
+            self.enable_feature_engine()?;
+            self.feature_engine_suspended = false;
+        }
+        if let Some(config) = self.accel_config {
+            self.set_accel_config(config)?;
+        }
+        if let Some(config) = self.gyro_config {
+            self.set_gyro_config(config)?;
+        }
+        Ok(())
+    }
+
+    /// Turn a sensor off by writing only the power mode field of its CONF register
+    pub(crate) fn set_power_mode(&mut self, reg: u8, mode: u16) -> Result<(), Error<E>> {
+        self.modify_word(reg, 0x7000, mode << 12)?;
+        match reg {
+            Register::ACC_CONF => self.accel_odr = None,
+            _ => self.gyro_odr = None,
+        }
         Ok(())
     }
 
@@ -111,20 +489,155 @@ where
     }
 
     fn read_sensor_data(&mut self, sensor_type: SensorType) -> Result<Sensor3DData, Error<E>> {
+        if self.start_pending_range(sensor_type)? {
+            self.wait_after_range_switch(drdy_mask(sensor_type))?;
+        }
+        self.read_sensor_burst(sensor_type)
+    }
+
+    /// Read one sensor's burst, without applying a pending range switch
+    fn read_sensor_burst(&mut self, sensor_type: SensorType) -> Result<Sensor3DData, Error<E>> {
         let (base_reg, data_size) = match sensor_type {
-            SensorType::Accelerometer => (Register::ACC_DATA_X, 21),
-            SensorType::Gyroscope => (Register::GYR_DATA_X, 15),
+            SensorType::Accelerometer => (Register::ACC_DATA_X, 20),
+            SensorType::Gyroscope => (Register::GYR_DATA_X, 14),
+        };
+
+        let mut data = [0u8; 20]; // Use the larger size
+        let sensor_data = &mut data[0..data_size];
+        self.read_burst(base_reg, sensor_data)?;
+
+        // Both bursts end with the saturation flags register
+        let len = sensor_data.len();
+        let saturation = SaturationFlags::from(u16::from_le_bytes([
+            sensor_data[len - 2],
+            sensor_data[len - 1],
+        ]));
+        let result = get_sensor3d_data(sensor_data);
+        self.update_auto_range(sensor_type, result, saturation);
+
+        Ok(result)
+    }
+
+    /// Enable automatic range switching
+    ///
+    /// Ranges are only switched for sensors configured through
+    /// [`Self::set_accel_config`] or [`Self::set_gyro_config`], since the rest
+    /// of the configuration is rewritten along with the new range.
+    ///
+    /// A switch is written by the next read of the sensor, and no sample is
+    /// returned until one measured at the new range is available. Blocking
+    /// reads wait for it for up to the configuration wait, or 100 ms if that
+    /// is disabled, and return [`Error::Timeout`] otherwise; the `try_read_*`
+    /// methods return `nb::Error::WouldBlock` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The auto-ranging settings
+    pub fn enable_auto_range(&mut self, config: AutoRangeConfig) {
+        self.auto_range = Some(AutoRange::new(config));
+    }
+
+    /// Disable automatic range switching
+    pub fn disable_auto_range(&mut self) {
+        self.auto_range = None;
+    }
+
+    /// Current accelerometer range used for scaling
+    pub fn accel_range(&self) -> AccelerometerRange {
+        self.accel_range
+    }
+
+    /// Current gyroscope range used for scaling
+    pub fn gyro_range(&self) -> GyroscopeRange {
+        self.gyro_range
+    }
+
+    /// Read and decode the saturation flags
+    pub fn read_saturation_flags(&mut self) -> Result<SaturationFlags, Error<E>> {
+        let flags = self.read_word(Register::SAT_FLAGS)?;
+        Ok(SaturationFlags::from(flags))
+    }
+
+    /// Decide on a range switch after a read, to be applied by the next one
+    fn update_auto_range(
+        &mut self,
+        sensor_type: SensorType,
+        data: Sensor3DData,
+        saturation: SaturationFlags,
+    ) {
+        let Some(auto_range) = self.auto_range.as_mut() else {
+            return;
+        };
+        let config = auto_range.config;
+
+        match sensor_type {
+            SensorType::Accelerometer => {
+                auto_range.pending_accel =
+                    match auto_range.accel.update(&config, saturation.accel(), data) {
+                        RangeStep::Keep => None,
+                        RangeStep::Up => self.accel_range.next_larger(),
+                        RangeStep::Down => self.accel_range.next_smaller(),
+                    };
+            }
+            SensorType::Gyroscope => {
+                auto_range.pending_gyro =
+                    match auto_range.gyro.update(&config, saturation.gyro(), data) {
+                        RangeStep::Keep => None,
+                        RangeStep::Up => self.gyro_range.next_larger(),
+                        RangeStep::Down => self.gyro_range.next_smaller(),
+                    };
+            }
+        }
+    }
+
+    /// Write a range switch decided by a previous read
+    ///
+    /// STATUS is read before the new range is written, clearing the data
+    /// ready flags, so the next flag set belongs to a sample measured at the
+    /// new range. Returns whether a switch was written. The switch is
+    /// dropped if it fails, so the error is returned once instead of by
+    /// every following read.
+    fn start_pending_range(&mut self, sensor_type: SensorType) -> Result<bool, Error<E>> {
+        let Some(auto_range) = self.auto_range.as_mut() else {
+            return Ok(false);
         };
 
-        let mut data = [0u8; 21]; // Use the larger size
-        data[0] = base_reg;
-        let sensor_data = self.read_data(&mut data[0..data_size])?;
+        match sensor_type {
+            SensorType::Accelerometer => {
+                let range = auto_range.pending_accel.take();
+                let (Some(range), Some(accel_config)) = (range, self.accel_config) else {
+                    return Ok(false);
+                };
+                self.read_register(Register::STATUS)?;
+                self.write_accel_config(AccelConfig {
+                    range,
+                    ..accel_config
+                })?;
+            }
+            SensorType::Gyroscope => {
+                let range = auto_range.pending_gyro.take();
+                let (Some(range), Some(gyro_config)) = (range, self.gyro_config) else {
+                    return Ok(false);
+                };
+                self.read_register(Register::STATUS)?;
+                self.write_gyro_config(GyroConfig {
+                    range,
+                    ..gyro_config
+                })?;
+            }
+        }
+
+        Ok(true)
+    }
 
-        Ok(Sensor3DData {
-            x: i16::from_le_bytes([sensor_data[0], sensor_data[1]]),
-            y: i16::from_le_bytes([sensor_data[2], sensor_data[3]]),
-            z: i16::from_le_bytes([sensor_data[4], sensor_data[5]]),
-        })
+    /// Wait for the first samples after a range switch
+    ///
+    /// # Arguments
+    ///
+    /// * `drdy` - The data ready bits to wait for
+    fn wait_after_range_switch(&mut self, drdy: u8) -> Result<(), Error<E>> {
+        let max_retries = self.config_wait_ms.unwrap_or(DEFAULT_CONFIG_WAIT_MS);
+        self.poll_data_ready(drdy, max_retries)
     }
 
     /// Read the LSB for the accelerometer
@@ -137,52 +650,381 @@ where
         self.read_sensor_data(SensorType::Gyroscope)
     }
 
-    /// Read the LSB for the accelerometer and return the scaled value as mps2
+    /// Read the LSB for the accelerometer and return the scaled value
+    ///
+    /// The unit is set with [`Self::set_output_units`], m/s² by default.
     pub fn read_accel_data_scaled(&mut self) -> Result<Sensor3DDataScaled, Error<E>> {
         let raw_data = self.read_accel_data()?;
-        Ok(raw_data.to_mps2(self.accel_range.to_g())) // Assuming 16-bit width
+        Ok(raw_data.to_accel_units_with_gravity(self.accel_range, self.accel_unit, self.gravity))
     }
 
-    /// Read the LSB for the gyroscope and return the scaled value as dps
+    /// Read the LSB for the gyroscope and return the scaled value
+    ///
+    /// The unit is set with [`Self::set_output_units`], °/s by default.
     pub fn read_gyro_data_scaled(&mut self) -> Result<Sensor3DDataScaled, Error<E>> {
         let raw_data = self.read_gyro_data()?;
-        Ok(raw_data.to_dps(self.gyro_range.to_dps())) // Assuming 16-bit width
+        Ok(raw_data.to_gyro_units(self.gyro_range, self.gyro_unit))
+    }
+
+    /// Read the accelerometer if a new sample is available
+    ///
+    /// Returns `nb::Error::WouldBlock` until the drdy_acc bit of STATUS is set.
+    pub fn try_read_accel(&mut self) -> nb::Result<Sensor3DData, Error<E>> {
+        self.try_read_sensor(SensorType::Accelerometer)
+    }
+
+    /// Read the gyroscope if a new sample is available
+    ///
+    /// Returns `nb::Error::WouldBlock` until the drdy_gyr bit of STATUS is set.
+    pub fn try_read_gyro(&mut self) -> nb::Result<Sensor3DData, Error<E>> {
+        self.try_read_sensor(SensorType::Gyroscope)
+    }
+
+    fn try_read_sensor(&mut self, sensor_type: SensorType) -> nb::Result<Sensor3DData, Error<E>> {
+        // After a range switch, the sample in the data registers is stale
+        if self.start_pending_range(sensor_type)? || !self.is_data_ready(sensor_type)? {
+            return Err(nb::Error::WouldBlock);
+        }
+        Ok(self.read_sensor_burst(sensor_type)?)
+    }
+
+    /// Read all sensor outputs once both sensors have a new sample
+    ///
+    /// Returns `nb::Error::WouldBlock` until the drdy_acc and drdy_gyr bits of
+    /// STATUS are both set.
+    pub fn try_read_all(&mut self) -> nb::Result<ImuData, Error<E>> {
+        // After a range switch, the sample in the data registers is stale
+        let accel_switched = self.start_pending_range(SensorType::Accelerometer)?;
+        let gyro_switched = self.start_pending_range(SensorType::Gyroscope)?;
+        if accel_switched || gyro_switched {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        let status = self.read_register(Register::STATUS)?;
+        if status & (DRDY_ACC | DRDY_GYR) != DRDY_ACC | DRDY_GYR {
+            return Err(nb::Error::WouldBlock);
+        }
+        Ok(self.read_all_burst()?)
+    }
+
+    /// Read accelerometer, gyroscope, temperature, sensor time and saturation
+    /// flags in a single burst so all values belong to the same sample
+    pub fn read_all(&mut self) -> Result<ImuData, Error<E>> {
+        let mut drdy = 0;
+        if self.start_pending_range(SensorType::Accelerometer)? {
+            drdy |= DRDY_ACC;
+        }
+        if self.start_pending_range(SensorType::Gyroscope)? {
+            drdy |= DRDY_GYR;
+        }
+        if drdy != 0 {
+            self.wait_after_range_switch(drdy)?;
+        }
+        self.read_all_burst()
+    }
+
+    /// Read the full burst, without applying pending range switches
+    fn read_all_burst(&mut self) -> Result<ImuData, Error<E>> {
+        let mut burst = [0u8; 20];
+        self.read_burst(Register::ACC_DATA_X, &mut burst)?;
+
+        let result = ImuData {
+            accel: get_sensor3d_data(&burst[0..6]),
+            gyro: get_sensor3d_data(&burst[6..12]),
+            temperature: i16::from_le_bytes([burst[12], burst[13]]),
+            sensor_time: u32::from_le_bytes([burst[14], burst[15], burst[16], burst[17]]),
+            saturation: SaturationFlags::from(u16::from_le_bytes([burst[18], burst[19]])),
+        };
+        self.update_auto_range(SensorType::Accelerometer, result.accel, result.saturation);
+        self.update_auto_range(SensorType::Gyroscope, result.gyro, result.saturation);
+
+        Ok(result)
+    }
+
+    /// Read all sensor outputs in a single burst and scale them using the
+    /// current accelerometer and gyroscope ranges and output units
+    pub fn read_all_scaled(&mut self) -> Result<ImuDataScaled, Error<E>> {
+        let raw_data = self.read_all()?;
+        Ok(self.scale_all(raw_data))
+    }
+
+    /// Configure the I2C watchdog
+    ///
+    /// The setting is cleared by the soft reset in `init`, so call this
+    /// afterwards. Only the watchdog bits of IO_I2C_IF are modified.
+    ///
+    /// # Arguments
+    ///
+    /// * `watchdog` - Whether and after which timeout SDA is released
+    pub fn set_i2c_watchdog(&mut self, watchdog: I2cWatchdog) -> Result<(), Error<E>> {
+        self.modify_word(Register::IO_I2C_IF, 0b11, u16::from(watchdog))
+    }
+
+    /// Read the I2C watchdog setting
+    pub fn get_i2c_watchdog(&mut self) -> Result<I2cWatchdog, Error<E>> {
+        let value = self.read_word(Register::IO_I2C_IF)?;
+        Ok(I2cWatchdog::from(value))
+    }
+
+    /// Set the output drive strength of the interface pads
+    ///
+    /// Applies to the SDA/SDO output pads. The setting is cleared by the soft
+    /// reset in `init`, so call this afterwards.
+    ///
+    /// The BMI323 has no drive strength setting for the INT1/INT2 pads; their
+    /// only electrical options are push-pull or open-drain output and the
+    /// active level in IO_INT_CTRL, which [`Self::map_interrupt`] sets to
+    /// active-high push-pull.
+    ///
+    /// # Arguments
+    ///
+    /// * `strength` - The drive strength level and I2C boost
+    pub fn set_pad_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Error<E>> {
+        self.modify_word(Register::IO_PAD_STRENGTH, 0b1111, u16::from(strength))
+    }
+
+    /// Read the output drive strength of the interface pads
+    pub fn get_pad_drive_strength(&mut self) -> Result<DriveStrength, Error<E>> {
+        let value = self.read_word(Register::IO_PAD_STRENGTH)?;
+        Ok(DriveStrength::from_register(value))
+    }
+
+    /// Configure the FIFO contents and watermark
+    ///
+    /// Needed before [`InterruptSource::FifoWatermark`] can fire. The FIFO is
+    /// flushed, so it only holds frames in the new format.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The FIFO settings
+    pub fn set_fifo_config(&mut self, config: FifoConfig) -> Result<(), Error<E>> {
+        config.validate().map_err(Error::InvalidConfig)?;
+        self.write_word(Register::FIFO_WATERMARK, config.watermark_words)?;
+        self.write_word(Register::FIFO_CONF, u16::from(config))?;
+        self.flush_fifo()
+    }
+
+    /// Discard the FIFO contents
+    pub fn flush_fifo(&mut self) -> Result<(), Error<E>> {
+        self.write_word(Register::FIFO_CTRL, 0x0001)
+    }
+
+    /// Read the number of words in the FIFO
+    pub fn read_fifo_fill_level(&mut self) -> Result<u16, Error<E>> {
+        Ok(self.read_word(Register::FIFO_FILL_LEVEL)? & 0x07FF)
+    }
+
+    /// Drain the FIFO into `data`
+    ///
+    /// Reads as many complete words as are available and fit into `data`, and
+    /// returns the number of bytes read.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Buffer receiving the raw FIFO contents
+    pub fn read_fifo(&mut self, data: &mut [u8]) -> Result<usize, Error<E>> {
+        let fill_level = usize::from(self.read_fifo_fill_level()?);
+        let len = (fill_level * 2).min(data.len() & !1);
+        self.read_burst(Register::FIFO_DATA, &mut data[..len])?;
+        Ok(len)
+    }
+
+    /// Route a data ready or FIFO watermark interrupt to a pin
+    ///
+    /// The pin is configured as an active-high push-pull output.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The interrupt source
+    /// * `pin` - The pin to signal on, or `None` to unmap the source
+    pub fn map_interrupt(
+        &mut self,
+        source: InterruptSource,
+        pin: Option<InterruptPin>,
+    ) -> Result<(), Error<E>> {
+        let shift = source as u16;
+        let value = pin.map_or(0, |pin| pin as u16);
+        self.modify_word(Register::INT_MAP2, 0b11 << shift, value << shift)?;
+        if let Some(pin) = pin {
+            self.enable_interrupt_pin(pin)?;
+        }
+        Ok(())
+    }
+
+    /// Enable the output of an interrupt pin, active high and push-pull
+    pub(crate) fn enable_interrupt_pin(&mut self, pin: InterruptPin) -> Result<(), Error<E>> {
+        // lvl, od and output_en in bits 0-2 for INT1 and 8-10 for INT2
+        let shift = match pin {
+            InterruptPin::Int1 => 0,
+            InterruptPin::Int2 => 8,
+        };
+        self.modify_word(Register::IO_INT_CTRL, 0b111 << shift, 0b101 << shift)
+    }
+
+    /// Read the raw 32-bit sensor time counter
+    ///
+    /// One tick is 39.0625 µs. Use [`crate::SensorClock`] to extend the value
+    /// across wraparounds.
+    pub fn read_sensor_time(&mut self) -> Result<u32, Error<E>> {
+        let mut time_data = [0u8; 4];
+        self.read_burst(Register::SENSOR_TIME_0, &mut time_data)?;
+        Ok(u32::from_le_bytes(time_data))
+    }
+
+    /// Read a 16-bit register
+    ///
+    /// Intended for registers the driver does not wrap yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The register address
+    pub fn read_word(&mut self, reg: u8) -> Result<u16, Error<E>> {
+        self.iface.read_word(reg).map_err(Error::Comm)
+    }
+
+    /// Write a 16-bit register
+    ///
+    /// Intended for registers the driver does not wrap yet. Writing registers
+    /// the driver manages (e.g. ACC_CONF) bypasses its cached state.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The register address
+    /// * `value` - The value to write
+    pub fn write_word(&mut self, reg: u8, value: u16) -> Result<(), Error<E>> {
+        self.iface.write_word(reg, value).map_err(Error::Comm)
     }
 
-    fn write_register_16bit(&mut self, reg: u8, value: u16) -> Result<(), Error<E>> {
-        let bytes = value.to_le_bytes();
-        self.iface.write_data(&[reg, bytes[0], bytes[1]])
+    /// Read-modify-write the bits selected by `mask` in a 16-bit register
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The register address
+    /// * `mask` - The bits to modify
+    /// * `value` - The new value of the masked bits
+    pub fn modify_word(&mut self, reg: u8, mask: u16, value: u16) -> Result<(), Error<E>> {
+        self.iface
+            .modify_word(reg, mask, value)
+            .map_err(Error::Comm)
     }
 
     fn read_register(&mut self, reg: u8) -> Result<u8, Error<E>> {
-        self.iface.read_register(reg)
+        self.iface.read_register(reg).map_err(Error::Comm)
     }
 
-    fn read_data<'a>(&mut self, data: &'a mut [u8]) -> Result<&'a [u8], Error<E>> {
-        self.iface.read_data(data)
+    /// Read consecutive registers in a single burst
+    ///
+    /// Intended for data the driver does not wrap yet, e.g. draining
+    /// FIFO_DATA. `data` can be of any length.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The register address to start reading from
+    /// * `data` - Buffer to store the read data
+    pub fn read_burst(&mut self, reg: u8, data: &mut [u8]) -> Result<(), Error<E>> {
+        self.iface.read_data(reg, data).map_err(Error::Comm)
     }
 
     fn wait_for_data_ready(&mut self, sensor_type: SensorType) -> Result<(), Error<E>> {
-        const MAX_RETRIES: u8 = 100;
+        let Some(max_retries) = self.config_wait_ms else {
+            return Ok(());
+        };
+        self.poll_data_ready(drdy_mask(sensor_type), max_retries)
+    }
+
+    /// Poll STATUS every millisecond until all `drdy` bits were seen
+    ///
+    /// Bits are collected across reads, since reading STATUS clears them.
+    fn poll_data_ready(&mut self, drdy: u8, max_retries: u32) -> Result<(), Error<E>> {
+        let mut seen = 0;
         let mut retries = 0;
 
-        while !self.is_data_ready(sensor_type)? {
-            if retries >= MAX_RETRIES {
+        loop {
+            seen |= self.read_register(Register::STATUS)?;
+            if seen & drdy == drdy {
+                return Ok(());
+            }
+            if retries >= max_retries {
                 return Err(Error::Timeout);
             }
             self.delay.delay_ms(1);
             retries += 1;
         }
+    }
 
-        Ok(())
+    pub(crate) fn enable_feature_engine(&mut self) -> Result<(), Error<E>> {
+        const MAX_RETRIES: u8 = 100;
+
+        self.write_word(Register::FEATURE_IO2, Register::FEATURE_ENGINE_STARTUP)?;
+        self.write_word(Register::FEATURE_IO_STATUS, 0x0001)?;
+        self.write_word(Register::FEATURE_CTRL, 0x0001)?;
+
+        // FEATURE_IO1.error_status reads 0x1 once the engine is running
+        for _ in 0..MAX_RETRIES {
+            self.delay.delay_ms(1);
+            if self.read_word(Register::FEATURE_IO1)? & 0x000F == 0x0001 {
+                return Ok(());
+            }
+        }
+        Err(Error::Timeout)
     }
 
     fn is_data_ready(&mut self, sensor_type: SensorType) -> Result<bool, Error<E>> {
         let status = self.read_register(Register::STATUS)?;
-        match sensor_type {
-            SensorType::Accelerometer => Ok((status & 0b1000_0000) != 0), // Check bit 7 (drdy_acc)
-            SensorType::Gyroscope => Ok((status & 0b0100_0000) != 0),     // Check bit 6 (drdy_gyr)
-        }
+        Ok(status & drdy_mask(sensor_type) != 0)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<DI, D, E> Bmi323<DI, D>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Wait for a data ready interrupt, then read all sensor outputs
+    ///
+    /// Route [`InterruptSource::AccelDataReady`] or
+    /// [`InterruptSource::GyroDataReady`] to the pin first with
+    /// [`Self::map_interrupt`]. Returns immediately if the pin is already
+    /// high.
+    ///
+    /// # Arguments
+    ///
+    /// * `pin` - The MCU input connected to INT1 or INT2
+    pub async fn wait_for_data<P: Wait>(
+        &mut self,
+        pin: &mut P,
+    ) -> Result<ImuData, Error<E, P::Error>> {
+        pin.wait_for_high().await.map_err(Error::Pin)?;
+        self.read_all().map_err(Error::with_pin_error)
+    }
+
+    /// Wait for a FIFO watermark interrupt, then drain the FIFO
+    ///
+    /// Set the FIFO contents and watermark with [`Self::set_fifo_config`] and
+    /// route [`InterruptSource::FifoWatermark`] to the pin with
+    /// [`Self::map_interrupt`] first. See [`Self::read_fifo`] for how much is
+    /// read.
+    ///
+    /// # Arguments
+    ///
+    /// * `pin` - The MCU input connected to INT1 or INT2
+    /// * `data` - Buffer receiving the raw FIFO contents
+    pub async fn wait_for_fifo<P: Wait>(
+        &mut self,
+        pin: &mut P,
+        data: &mut [u8],
+    ) -> Result<usize, Error<E, P::Error>> {
+        pin.wait_for_high().await.map_err(Error::Pin)?;
+        self.read_fifo(data).map_err(Error::with_pin_error)
+    }
+}
+
+fn get_sensor3d_data(data: &[u8]) -> Sensor3DData {
+    Sensor3DData {
+        x: i16::from_le_bytes([data[0], data[1]]),
+        y: i16::from_le_bytes([data[2], data[3]]),
+        z: i16::from_le_bytes([data[4], data[5]]),
     }
 }
 
@@ -190,14 +1032,6 @@ where
 mod tests {
     use super::*;
 
-    fn get_sensor3d_data(data: &[u8]) -> Sensor3DData {
-        Sensor3DData {
-            x: i16::from_le_bytes([data[0], data[1]]),
-            y: i16::from_le_bytes([data[2], data[3]]),
-            z: i16::from_le_bytes([data[4], data[5]]),
-        }
-    }
-
     mod sensor3d_data {
         use super::*;
 
@@ -1,5 +1,20 @@
-use crate::Error;
-use embedded_hal::{i2c, spi::SpiDevice};
+//! Bus interfaces for the BMI323
+//!
+//! The driver talks to the device through the [`ReadData`] and [`WriteData`]
+//! traits. [`I2cInterface`] and [`SpiInterface`] implement them on top of
+//! `embedded-hal`; any other transport (an FPGA register window, a USB bridge,
+//! a simulator...) can implement them as well and be passed to
+//! [`Bmi323::new`](crate::Bmi323::new).
+//!
+//! BMI323 registers are 16 bits wide and transferred LSB first. A transport
+//! only needs to provide [`ReadData::read_data`] and [`WriteData::write_data`];
+//! everything else has a default implementation built on those two.
+
+use crate::types::SpiMode;
+use embedded_hal::{
+    i2c,
+    spi::{Operation, SpiDevice},
+};
 
 /// I2C communication interface for BMI323
 #[derive(Debug)]
@@ -9,13 +24,48 @@ pub struct I2cInterface<I2C> {
 }
 
 /// SPI communication interface for BMI323
+///
+/// Every access is a single `SpiDevice` transaction made of write and read
+/// operations, so it also works on half-duplex buses in 3-wire mode.
 #[derive(Debug)]
 pub struct SpiInterface<SPI> {
     pub(crate) spi: SPI,
+    pub(crate) mode: SpiMode,
+}
+
+impl<I2C> I2cInterface<I2C> {
+    /// Create an I2C interface for the device at `address`
+    pub fn new(i2c: I2C, address: impl Into<u8>) -> Self {
+        I2cInterface {
+            i2c,
+            address: address.into(),
+        }
+    }
+
+    /// Release the I2C bus
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<SPI> SpiInterface<SPI> {
+    /// Create an SPI interface using the given wire mode
+    pub fn new(spi: SPI, mode: SpiMode) -> Self {
+        SpiInterface { spi, mode }
+    }
+
+    /// Release the SPI device
+    pub fn release(self) -> SPI {
+        self.spi
+    }
 }
 
 /// Trait for writing data to the BMI323
+///
+/// Interfaces only need to implement `write_data`; single byte and word
+/// writes and read-modify-write are built on top of it.
 pub trait WriteData {
+    /// Error type of the underlying transport
     type Error;
     /// Write a single byte to a register
     ///
@@ -23,27 +73,62 @@ pub trait WriteData {
     ///
     /// * `register` - The register address
     /// * `data` - The byte to write
-    fn write_register(&mut self, register: u8, data: u8) -> Result<(), Self::Error>;
-    /// Write multiple bytes of data
+    fn write_register(&mut self, register: u8, data: u8) -> Result<(), Self::Error> {
+        self.write_data(register, &[data])
+    }
+    /// Write a 16-bit word to a register
     ///
     /// # Arguments
     ///
-    /// * `payload` - The data to write
-    fn write_data(&mut self, payload: &[u8]) -> Result<(), Self::Error>;
+    /// * `register` - The register address
+    /// * `data` - The word to write, sent LSB first
+    fn write_word(&mut self, register: u8, data: u16) -> Result<(), Self::Error> {
+        self.write_data(register, &data.to_le_bytes())
+    }
+    /// Read-modify-write the bits selected by `mask` in a 16-bit register
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The register address
+    /// * `mask` - The bits to modify
+    /// * `value` - The new value of the masked bits
+    fn modify_word(
+        &mut self,
+        register: u8,
+        mask: u16,
+        value: u16,
+    ) -> Result<(), <Self as WriteData>::Error>
+    where
+        Self: ReadData<Error = <Self as WriteData>::Error>,
+    {
+        let current = self.read_word(register)?;
+        self.write_word(register, (current & !mask) | (value & mask))
+    }
+    /// Write multiple bytes of data, starting at a register
+    ///
+    /// The register address and the data must be sent in one bus transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The register address to start writing to
+    /// * `data` - The data to write
+    fn write_data(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error>;
 }
 
 impl<I2C, E> WriteData for I2cInterface<I2C>
 where
     I2C: i2c::I2c<Error = E>,
 {
-    type Error = Error<E>;
-    fn write_register(&mut self, register: u8, data: u8) -> Result<(), Self::Error> {
-        let payload: [u8; 2] = [register, data];
-        self.i2c.write(self.address, &payload).map_err(Error::Comm)
-    }
-
-    fn write_data(&mut self, payload: &[u8]) -> Result<(), Self::Error> {
-        self.i2c.write(self.address, payload).map_err(Error::Comm)
+    type Error = E;
+    fn write_data(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error> {
+        // Adjacent writes are sent without a repeated start
+        self.i2c.transaction(
+            self.address,
+            &mut [
+                i2c::Operation::Write(&[register]),
+                i2c::Operation::Write(data),
+            ],
+        )
     }
 }
 
@@ -51,66 +136,75 @@ impl<SPI, E> WriteData for SpiInterface<SPI>
 where
     SPI: SpiDevice<Error = E>,
 {
-    type Error = Error<E>;
-    fn write_register(&mut self, register: u8, data: u8) -> Result<(), Self::Error> {
-        let payload: [u8; 2] = [register, data];
-        self.spi.write(&payload).map_err(Error::Comm)
-    }
-
-    fn write_data(&mut self, payload: &[u8]) -> Result<(), Self::Error> {
-        self.spi.write(payload).map_err(Error::Comm)
+    type Error = E;
+    fn write_data(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.spi
+            .transaction(&mut [Operation::Write(&[register]), Operation::Write(data)])
     }
 }
 
+/// Trait for reading data from the BMI323
+///
+/// Interfaces only need to implement `read_data`; single byte and word reads
+/// are built on top of it.
 pub trait ReadData {
+    /// Error type of the underlying transport
     type Error;
+    /// SPI wire mode of the interface, `None` for I2C
+    ///
+    /// The BMI323 starts in I2C mode. For SPI interfaces, `init` switches the
+    /// device over with a dummy read after power-on and after the soft reset,
+    /// and enables 3-wire mode if needed.
+    fn spi_mode(&self) -> Option<SpiMode> {
+        None
+    }
     /// Read a single byte from a register
     ///
     /// # Arguments
     ///
     /// * `register` - The register address to read from
-    fn read_register(&mut self, register: u8) -> Result<u8, Self::Error>;
-    /// Read multiple bytes of data
+    fn read_register(&mut self, register: u8) -> Result<u8, Self::Error> {
+        let mut data = [0u8; 1];
+        self.read_data(register, &mut data)?;
+        Ok(data[0])
+    }
+    /// Read a 16-bit word from a register
     ///
     /// # Arguments
     ///
-    /// * `payload` - Buffer to store the read data
-    fn read_data<'a>(&mut self, payload: &'a mut [u8]) -> Result<&'a [u8], Self::Error>;
+    /// * `register` - The register address to read from
+    fn read_word(&mut self, register: u8) -> Result<u16, Self::Error> {
+        let mut data = [0u8; 2];
+        self.read_data(register, &mut data)?;
+        Ok(u16::from_le_bytes(data))
+    }
+    /// Read multiple bytes of data, starting at a register
+    ///
+    /// The dummy bytes sent by the device are discarded, so `data` only
+    /// receives register contents. There is no limit on the length.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - The register address to start reading from
+    /// * `data` - Buffer to store the read data
+    fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Self::Error>;
 }
 
 impl<I2C, E> ReadData for I2cInterface<I2C>
 where
     I2C: i2c::I2c<Error = E>,
 {
-    type Error = Error<E>;
-    fn read_register(&mut self, register: u8) -> Result<u8, Self::Error> {
-        let mut temp_data = [0u8; 128];
-        let mut data = [0u8; 2];
-        self.i2c
-            .write_read(self.address, &[register], &mut temp_data)
-            .map_err(Error::Comm)?;
-        for i in 0..data.len() {
-            data[i] = temp_data[i + 2];
-        }
-        Ok(data[0])
-    }
-
-    fn read_data<'a>(&mut self, payload: &'a mut [u8]) -> Result<&'a [u8], Error<E>> {
-        let address = payload[0];
-        let len = payload.len();
-        let data = &mut payload[1..len];
-
-        let total_len = data.len() + 2;
-        let mut temp_buf = [0u8; 128]; // Temporary buffer to hold dummy bytes and data
-
-        self.i2c
-            .write_read(self.address, &[address], &mut temp_buf[..total_len])
-            .map_err(Error::Comm)?;
-
-        // Copy data from temp_buf to data, skipping dummy bytes
-        data.copy_from_slice(&temp_buf[2..total_len]);
-
-        Ok(data)
+    type Error = E;
+    fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        let mut dummy = [0u8; 2]; // I2C reads start with 2 dummy bytes
+        self.i2c.transaction(
+            self.address,
+            &mut [
+                i2c::Operation::Write(&[register]),
+                i2c::Operation::Read(&mut dummy),
+                i2c::Operation::Read(data),
+            ],
+        )
     }
 }
 
@@ -118,24 +212,17 @@ impl<SPI, E> ReadData for SpiInterface<SPI>
 where
     SPI: SpiDevice<Error = E>,
 {
-    type Error = Error<E>;
-    fn read_register(&mut self, register: u8) -> Result<u8, Self::Error> {
-        let mut data = [register | 0x80, 0, 0]; // Add read bit and 1 dummy byte
-        self.spi.transfer_in_place(&mut data).map_err(Error::Comm)?;
-        Ok(data[2]) // Return the actual data byte, skipping dummy byte
+    type Error = E;
+    fn spi_mode(&self) -> Option<SpiMode> {
+        Some(self.mode)
     }
 
-    fn read_data<'a>(&mut self, payload: &'a mut [u8]) -> Result<&'a [u8], Self::Error> {
-        let len = payload.len();
-        let mut temp_buf = [0u8; 128]; // Temporary buffer to hold read bit, dummy byte, and data
-        temp_buf[0] = payload[0] | 0x80; // Add read bit to register address
-
-        self.spi
-            .transfer_in_place(&mut temp_buf[..len + 1])
-            .map_err(Error::Comm)?; // +1 for dummy byte
-
-        // Copy data from temp_buf to payload, skipping dummy byte
-        payload[1..].copy_from_slice(&temp_buf[2..len + 1]);
-        Ok(&payload[1..])
+    fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        let mut dummy = [0u8; 1]; // SPI reads start with 1 dummy byte
+        self.spi.transaction(&mut [
+            Operation::Write(&[register | 0x80]), // Add read bit
+            Operation::Read(&mut dummy),
+            Operation::Read(data),
+        ])
     }
 }
@@ -1,21 +1,44 @@
 #![no_std]
 
+mod auto_range;
 /// BMI323 driver for Rust
 ///
 /// This module provides a high-level interface for interacting with the Bosch BMI323 IMU.
 /// It supports both I2C and SPI interfaces and allows for configuration of accelerometer
 /// and gyroscope settings.
 pub mod device;
-mod interface;
+pub use auto_range::AutoRangeConfig;
+pub use device::{probe_i2c, scan_i2c};
+pub mod interface;
+pub use interface::{I2cInterface, ReadData, SpiInterface, WriteData};
 mod registers;
 pub use registers::Register;
 mod types;
 pub use types::{
-    AccelerometerPowerMode, AccelerometerRange, AverageNum, Bandwidth, Error, GyroscopePowerMode,
-    GyroscopeRange, OutputDataRate, Sensor3DData, Sensor3DDataScaled,
+    AccelUnit, AccelerometerPowerMode, AccelerometerRange, AverageNum, Bandwidth, ConfigError,
+    DeviceErrors, DriveStrength, Error, FifoConfig, GyroUnit, GyroscopePowerMode, GyroscopeRange,
+    I2cWatchdog, ImuData, ImuDataScaled, InterruptPin, InterruptSource, OutputDataRate,
+    SaturationFlags, Sensor3DData, Sensor3DDataMilli, Sensor3DDataScaled, SensorType, SlaveAddress,
+    SpiMode,
 };
 mod sensor_data;
 pub use sensor_data::*;
+mod sensor_time;
+pub use sensor_time::{ticks_to_duration, SensorClock, SENSOR_TIME_TICKS_PER_SECOND};
+mod power;
+pub use power::{estimate_current_ua, PowerModel};
+pub mod trace;
+pub use trace::{TraceRing, Traced};
+pub mod typestate;
+pub use typestate::TypedBmi323;
+mod sample_stream;
+pub use sample_stream::{ImuSample, SampleStream, Timebase};
+mod wake_on_motion;
+pub use wake_on_motion::{WakeOnMotion, WakeOnMotionConfig};
+#[cfg(feature = "units")]
+pub mod units;
+#[cfg(feature = "units")]
+pub use units::{Acceleration, AngularRate};
 
 /// Main struct representing the BMI323 device
 pub struct Bmi323<DI, D> {
@@ -27,6 +50,30 @@ pub struct Bmi323<DI, D> {
     accel_range: AccelerometerRange,
     /// Current gyroscope range
     gyro_range: GyroscopeRange,
+    /// Last accelerometer configuration written by `set_accel_config`
+    ///
+    /// Restored by `resume`; configuration readback does not touch it.
+    accel_config: Option<AccelConfig>,
+    /// Last gyroscope configuration written by `set_gyro_config`
+    ///
+    /// Restored by `resume`; configuration readback does not touch it.
+    gyro_config: Option<GyroConfig>,
+    /// Output data rate of the accelerometer, `None` while it is off
+    accel_odr: Option<OutputDataRate>,
+    /// Output data rate of the gyroscope, `None` while it is off
+    gyro_odr: Option<OutputDataRate>,
+    /// Auto-ranging state, if enabled
+    auto_range: Option<auto_range::AutoRange>,
+    /// Whether `suspend` turned the feature engine off
+    feature_engine_suspended: bool,
+    /// How long configuration changes wait for the first sample, in ms
+    config_wait_ms: Option<u32>,
+    /// Unit of scaled accelerometer data
+    accel_unit: AccelUnit,
+    /// Unit of scaled gyroscope data
+    gyro_unit: GyroUnit,
+    /// Gravity used to convert to m/s^2
+    gravity: f32,
 }
 
 /// Configuration for the accelerometer
@@ -49,9 +96,30 @@ impl AccelConfig {
     pub fn builder() -> AccelConfigBuilder {
         AccelConfigBuilder::default()
     }
+
+    /// Whether the configuration produces accelerometer data
+    pub fn is_enabled(&self) -> bool {
+        self.mode != AccelerometerPowerMode::Disable
+    }
+
+    /// Check the configuration against the datasheet constraints
+    ///
+    /// Low power mode supports up to 400 Hz and requires the averaged samples
+    /// to fit in one ODR period. Normal and high performance modes support
+    /// 12.5 Hz and above without averaging.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match self.mode {
+            AccelerometerPowerMode::Disable => Ok(()),
+            AccelerometerPowerMode::LowPower => validate_low_power(self.odr, self.avg_num),
+            AccelerometerPowerMode::Normal | AccelerometerPowerMode::HighPerf => {
+                validate_continuous(self.odr, self.avg_num)
+            }
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Builder for AccelConfig
+#[derive(Debug, Clone, Copy, Default)]
 pub struct AccelConfigBuilder {
     odr: Option<OutputDataRate>,
     range: Option<AccelerometerRange>,
@@ -60,19 +128,6 @@ pub struct AccelConfigBuilder {
     mode: Option<AccelerometerPowerMode>,
 }
 
-/// Builder for AccelConfig
-impl Default for AccelConfigBuilder {
-    fn default() -> Self {
-        Self {
-            odr: None,
-            range: None,
-            bw: None,
-            avg_num: None,
-            mode: None,
-        }
-    }
-}
-
 impl AccelConfigBuilder {
     /// Set the output data rate
     pub fn odr(mut self, odr: OutputDataRate) -> Self {
@@ -136,10 +191,63 @@ impl GyroConfig {
     pub fn builder() -> GyroConfigBuilder {
         GyroConfigBuilder::default()
     }
+
+    /// Whether the configuration produces gyroscope data
+    ///
+    /// In suspend mode the drive is kept running but no data is produced.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(
+            self.mode,
+            GyroscopePowerMode::Disable | GyroscopePowerMode::Suspend
+        )
+    }
+
+    /// Check the configuration against the datasheet constraints
+    ///
+    /// Same rules as [`AccelConfig::validate`], except that 6.4 kHz is only
+    /// available in high performance mode.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match self.mode {
+            GyroscopePowerMode::Disable | GyroscopePowerMode::Suspend => Ok(()),
+            GyroscopePowerMode::LowPower => validate_low_power(self.odr, self.avg_num),
+            GyroscopePowerMode::Normal => {
+                if self.odr == OutputDataRate::Odr6400hz {
+                    return Err(ConfigError::OdrNotSupported);
+                }
+                validate_continuous(self.odr, self.avg_num)
+            }
+            GyroscopePowerMode::HighPerf => validate_continuous(self.odr, self.avg_num),
+        }
+    }
+}
+
+/// Check ODR and averaging for a sensor in low power mode
+fn validate_low_power(odr: OutputDataRate, avg_num: AverageNum) -> Result<(), ConfigError> {
+    if odr as u8 > OutputDataRate::Odr400hz as u8 {
+        return Err(ConfigError::OdrNotSupported);
+    }
+    // Averaging 2^n samples skips 2^n - 1 of the 6.4 kHz base periods
+    let base_periods = 1u32 << odr.base_periods_log2();
+    let skipped = (1u32 << (avg_num as u8)) - 1;
+    if base_periods <= skipped {
+        return Err(ConfigError::AveragingTooLong);
+    }
+    Ok(())
+}
+
+/// Check ODR and averaging for a sensor in normal or high performance mode
+fn validate_continuous(odr: OutputDataRate, avg_num: AverageNum) -> Result<(), ConfigError> {
+    if (odr as u8) < OutputDataRate::Odr12_5hz as u8 {
+        return Err(ConfigError::OdrNotSupported);
+    }
+    if avg_num != AverageNum::Avg1 {
+        return Err(ConfigError::AveragingNotSupported);
+    }
+    Ok(())
 }
 
 /// Builder for GyroConfig
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct GyroConfigBuilder {
     odr: Option<OutputDataRate>,
     range: Option<GyroscopeRange>,
@@ -148,18 +256,6 @@ pub struct GyroConfigBuilder {
     mode: Option<GyroscopePowerMode>,
 }
 
-impl Default for GyroConfigBuilder {
-    fn default() -> Self {
-        Self {
-            odr: None,
-            range: None,
-            bw: None,
-            avg_num: None,
-            mode: None,
-        }
-    }
-}
-
 impl GyroConfigBuilder {
     /// Set the output data rate
     pub fn odr(mut self, odr: OutputDataRate) -> Self {
@@ -224,3 +320,33 @@ impl From<GyroConfig> for u16 {
             | ((config.mode as u16 & 0x07) << 12)
     }
 }
+
+impl TryFrom<u16> for AccelConfig {
+    type Error = ConfigError;
+
+    /// Decode a 16-bit ACC_CONF register value, rejecting reserved codes
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(AccelConfig {
+            odr: OutputDataRate::try_from((value & 0x0F) as u8)?,
+            range: AccelerometerRange::try_from(((value >> 4) & 0x07) as u8)?,
+            bw: Bandwidth::from((value >> 7) & 0x01 != 0),
+            avg_num: AverageNum::try_from(((value >> 8) & 0x07) as u8)?,
+            mode: AccelerometerPowerMode::try_from(((value >> 12) & 0x07) as u8)?,
+        })
+    }
+}
+
+impl TryFrom<u16> for GyroConfig {
+    type Error = ConfigError;
+
+    /// Decode a 16-bit GYR_CONF register value, rejecting reserved codes
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Ok(GyroConfig {
+            odr: OutputDataRate::try_from((value & 0x0F) as u8)?,
+            range: GyroscopeRange::try_from(((value >> 4) & 0x07) as u8)?,
+            bw: Bandwidth::from((value >> 7) & 0x01 != 0),
+            avg_num: AverageNum::try_from(((value >> 8) & 0x07) as u8)?,
+            mode: GyroscopePowerMode::try_from(((value >> 12) & 0x07) as u8)?,
+        })
+    }
+}
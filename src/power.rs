@@ -0,0 +1,107 @@
+use crate::{
+    AccelConfig, AccelerometerPowerMode, AverageNum, GyroConfig, GyroscopePowerMode, OutputDataRate,
+};
+
+/// Supply current figures used to estimate the consumption of a configuration
+///
+/// All values are in µA. The defaults are typical figures at VDDIO = VDD =
+/// 1.8 V and 25 °C; override them with the values of your datasheet revision
+/// or with measurements of your board.
+///
+/// Low power modes only run the sensor while sampling: with averaging over
+/// 2^n samples, the sensor is active for 2^n of the 6.4 kHz base periods in
+/// every output period and draws its normal mode current meanwhile. The
+/// gyroscope drive keeps running in between and draws `gyro_suspend_ua`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerModel {
+    /// Chip with both sensors and the feature engine off
+    pub suspend_ua: f32,
+    /// Additional current of the accelerometer in normal mode
+    pub accel_normal_ua: f32,
+    /// Additional current of the accelerometer in high performance mode
+    pub accel_high_perf_ua: f32,
+    /// Additional current of the gyroscope in normal mode
+    pub gyro_normal_ua: f32,
+    /// Additional current of the gyroscope in high performance mode
+    pub gyro_high_perf_ua: f32,
+    /// Additional current of the gyroscope drive in suspend mode
+    pub gyro_suspend_ua: f32,
+    /// Additional current of the running feature engine
+    pub feature_engine_ua: f32,
+}
+
+impl Default for PowerModel {
+    fn default() -> Self {
+        PowerModel {
+            suspend_ua: 10.0,
+            accel_normal_ua: 170.0,
+            accel_high_perf_ua: 180.0,
+            gyro_normal_ua: 560.0,
+            gyro_high_perf_ua: 600.0,
+            gyro_suspend_ua: 270.0,
+            feature_engine_ua: 20.0,
+        }
+    }
+}
+
+impl PowerModel {
+    /// Estimate the typical supply current of a configuration, in µA
+    ///
+    /// # Arguments
+    ///
+    /// * `accel` - The accelerometer configuration
+    /// * `gyro` - The gyroscope configuration
+    /// * `feature_engine` - Whether the feature engine is running
+    pub fn estimate_current_ua(
+        &self,
+        accel: &AccelConfig,
+        gyro: &GyroConfig,
+        feature_engine: bool,
+    ) -> f32 {
+        let accel_ua = match accel.mode {
+            AccelerometerPowerMode::Disable => 0.0,
+            AccelerometerPowerMode::LowPower => {
+                self.accel_normal_ua * duty_cycle(accel.odr, accel.avg_num)
+            }
+            AccelerometerPowerMode::Normal => self.accel_normal_ua,
+            AccelerometerPowerMode::HighPerf => self.accel_high_perf_ua,
+        };
+        let gyro_ua = match gyro.mode {
+            GyroscopePowerMode::Disable => 0.0,
+            GyroscopePowerMode::Suspend => self.gyro_suspend_ua,
+            GyroscopePowerMode::LowPower => {
+                self.gyro_suspend_ua
+                    + (self.gyro_normal_ua - self.gyro_suspend_ua)
+                        * duty_cycle(gyro.odr, gyro.avg_num)
+            }
+            GyroscopePowerMode::Normal => self.gyro_normal_ua,
+            GyroscopePowerMode::HighPerf => self.gyro_high_perf_ua,
+        };
+        let engine_ua = if feature_engine {
+            self.feature_engine_ua
+        } else {
+            0.0
+        };
+        self.suspend_ua + accel_ua + gyro_ua + engine_ua
+    }
+}
+
+/// Estimate the typical supply current of a configuration, in µA
+///
+/// Uses the default [`PowerModel`] figures.
+///
+/// # Arguments
+///
+/// * `accel` - The accelerometer configuration
+/// * `gyro` - The gyroscope configuration
+/// * `feature_engine` - Whether the feature engine is running
+pub fn estimate_current_ua(accel: &AccelConfig, gyro: &GyroConfig, feature_engine: bool) -> f32 {
+    PowerModel::default().estimate_current_ua(accel, gyro, feature_engine)
+}
+
+/// Fraction of time a sensor in low power mode is sampling
+fn duty_cycle(odr: OutputDataRate, avg_num: AverageNum) -> f32 {
+    let active = 1u32 << (avg_num as u8);
+    let period = 1u32 << odr.base_periods_log2();
+    (active as f32 / period as f32).min(1.0)
+}
@@ -11,14 +11,64 @@ impl Register {
     pub const ACC_DATA_X: u8 = 0x03;
     /// Gyroscope X-axis data register address
     pub const GYR_DATA_X: u8 = 0x06;
+    /// Temperature data register address
+    pub const TEMP_DATA: u8 = 0x09;
+    /// Sensor time low word register address
+    pub const SENSOR_TIME_0: u8 = 0x0A;
+    /// Sensor time high word register address
+    pub const SENSOR_TIME_1: u8 = 0x0B;
+    /// Saturation flags register address
+    pub const SAT_FLAGS: u8 = 0x0C;
+    /// INT1 interrupt status register address
+    pub const INT_STATUS_INT1: u8 = 0x0D;
+    /// INT2 interrupt status register address
+    pub const INT_STATUS_INT2: u8 = 0x0E;
+    /// Feature enable register address
+    pub const FEATURE_IO0: u8 = 0x10;
+    /// Feature engine error status register address
+    pub const FEATURE_IO1: u8 = 0x11;
+    /// Feature engine start-up configuration register address
+    pub const FEATURE_IO2: u8 = 0x12;
+    /// Feature engine I/O synchronization register address
+    pub const FEATURE_IO_STATUS: u8 = 0x14;
+    /// FIFO fill level register address, in words
+    pub const FIFO_FILL_LEVEL: u8 = 0x15;
+    /// FIFO data register address
+    pub const FIFO_DATA: u8 = 0x16;
     /// Accelerometer configuration register address
     pub const ACC_CONF: u8 = 0x20;
     /// Gyroscope configuration register address
     pub const GYR_CONF: u8 = 0x21;
+    /// FIFO watermark register address, in words
+    pub const FIFO_WATERMARK: u8 = 0x35;
+    /// FIFO configuration register address
+    pub const FIFO_CONF: u8 = 0x36;
+    /// FIFO control register address
+    pub const FIFO_CTRL: u8 = 0x37;
+    /// Interrupt pin configuration register address
+    pub const IO_INT_CTRL: u8 = 0x38;
+    /// Feature interrupt mapping register address
+    pub const INT_MAP1: u8 = 0x3A;
+    /// Data and FIFO interrupt mapping register address
+    pub const INT_MAP2: u8 = 0x3B;
+    /// Feature engine control register address
+    pub const FEATURE_CTRL: u8 = 0x40;
+    /// Extended register address selection
+    pub const FEATURE_DATA_ADDR: u8 = 0x41;
+    /// Extended register data
+    pub const FEATURE_DATA_TX: u8 = 0x42;
+    /// SPI interface configuration register address
+    pub const IO_SPI_IF: u8 = 0x50;
+    /// Pad drive strength register address
+    pub const IO_PAD_STRENGTH: u8 = 0x51;
+    /// I2C interface configuration register address
+    pub const IO_I2C_IF: u8 = 0x52;
     /// Command register address
     pub const CMD: u8 = 0x7E;
     /// Expected chip ID for BMI323
     pub const BMI323_CHIP_ID: u8 = 0x43;
     /// Soft reset command value
     pub const CMD_SOFT_RESET: u16 = 0xDEAF;
+    /// FEATURE_IO2 value required before enabling the feature engine
+    pub const FEATURE_ENGINE_STARTUP: u16 = 0x012C;
 }
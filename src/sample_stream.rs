@@ -0,0 +1,154 @@
+use core::time::Duration;
+
+use crate::{
+    interface::{ReadData, WriteData},
+    sensor_time::{ticks_to_duration, SensorClock},
+    Bmi323, ConfigError, Error, OutputDataRate, Sensor3DDataScaled,
+};
+use embedded_hal::delay::DelayNs;
+
+/// A scaled sample with its timestamp
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuSample {
+    /// Accelerometer data in the driver's output unit, m/s^2 by default
+    pub accel: Sensor3DDataScaled,
+    /// Gyroscope data in the driver's output unit, degrees per second by default
+    pub gyro: Sensor3DDataScaled,
+    /// Temperature in degrees Celsius
+    pub temperature: f32,
+    /// Timestamp of the sample, see [`Timebase`]
+    pub timestamp: Duration,
+}
+
+/// Source of the sample timestamps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Timebase {
+    /// Sensor time read along with each sample, extended across wraparounds
+    ///
+    /// Timestamps count from the sensor time origin. SENSOR_TIME is latched
+    /// when the burst read reaches it, so a timestamp trails the sampling
+    /// instant by up to one output data rate period plus the polling delay.
+    SensorTime,
+    /// Sensor time rounded to a grid of output data rate periods
+    ///
+    /// The first sample is at zero and later ones at the nearest whole number
+    /// of periods of sensor time after it, so periods missed between two
+    /// polls are skipped instead of shifting later timestamps. The period is
+    /// taken from the configured output data rate.
+    ///
+    /// The stream reads the data registers, not the FIFO; timestamping FIFO
+    /// frames by their count is not provided.
+    SensorTimeGrid,
+}
+
+/// Stream of timestamped samples read from a [`Bmi323`]
+///
+/// Both sensors must be enabled through [`Bmi323::set_accel_config`] and
+/// [`Bmi323::set_gyro_config`] with the same output data rate. Each new
+/// sample is read in a single burst once both data ready flags are set.
+/// [`Self::next_sample`] does not block; the [`Iterator`] implementation
+/// polls STATUS until a sample is available and never ends.
+///
+/// If a sensor is off, for example after [`Bmi323::suspend`], or the output
+/// data rates differ, both return [`ConfigError::SensorDisabled`] or
+/// [`ConfigError::OdrMismatch`] instead of waiting for data.
+pub struct SampleStream<'a, DI, D> {
+    imu: &'a mut Bmi323<DI, D>,
+    timebase: Timebase,
+    clock: SensorClock,
+    /// Extended sensor time of the first sample
+    first_ticks: Option<u64>,
+    samples: u64,
+}
+
+impl<'a, DI, D> SampleStream<'a, DI, D> {
+    /// Create a stream reading from `imu`
+    ///
+    /// # Arguments
+    ///
+    /// * `imu` - The configured device
+    /// * `timebase` - How samples are timestamped
+    pub fn new(imu: &'a mut Bmi323<DI, D>, timebase: Timebase) -> Self {
+        SampleStream {
+            imu,
+            timebase,
+            clock: SensorClock::new(),
+            first_ticks: None,
+            samples: 0,
+        }
+    }
+}
+
+impl<DI, D, E> SampleStream<'_, DI, D>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Read the next sample if one is available
+    ///
+    /// Returns `nb::Error::WouldBlock` until both sensors have new data.
+    pub fn next_sample(&mut self) -> nb::Result<ImuSample, Error<E>> {
+        let odr = self.imu.stream_odr().map_err(Error::InvalidConfig)?;
+        let raw_data = self.imu.try_read_all()?;
+        let data = self.imu.scale_all(raw_data);
+
+        let ticks = self.clock.update(data.sensor_time);
+        let timestamp = match self.timebase {
+            Timebase::SensorTime => ticks_to_duration(ticks),
+            Timebase::SensorTimeGrid => {
+                let first = *self.first_ticks.get_or_insert(ticks);
+                let elapsed = ticks_to_duration(ticks - first).as_nanos() as u64;
+                // Round to the nearest period; multiply in nanoseconds to
+                // avoid accumulating rounding errors
+                let period = odr.period().as_nanos() as u64;
+                let periods = (elapsed + period / 2) / period;
+                Duration::from_nanos(period * periods)
+            }
+        };
+        self.samples += 1;
+
+        Ok(ImuSample {
+            accel: data.accel,
+            gyro: data.gyro,
+            temperature: data.temperature,
+            timestamp,
+        })
+    }
+
+    /// Number of samples read so far
+    pub fn sample_count(&self) -> u64 {
+        self.samples
+    }
+}
+
+impl<DI, D, E> Iterator for SampleStream<'_, DI, D>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    type Item = Result<ImuSample, Error<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(nb::block!(self.next_sample()))
+    }
+}
+
+impl<DI, D> Bmi323<DI, D> {
+    /// Stream timestamped samples from the device
+    ///
+    /// # Arguments
+    ///
+    /// * `timebase` - How samples are timestamped
+    pub fn samples(&mut self, timebase: Timebase) -> SampleStream<'_, DI, D> {
+        SampleStream::new(self, timebase)
+    }
+
+    /// Output data rate shared by both sensors, as required by [`SampleStream`]
+    fn stream_odr(&self) -> Result<OutputDataRate, ConfigError> {
+        match (self.accel_odr, self.gyro_odr) {
+            (Some(accel), Some(gyro)) if accel == gyro => Ok(accel),
+            (Some(_), Some(_)) => Err(ConfigError::OdrMismatch),
+            _ => Err(ConfigError::SensorDisabled),
+        }
+    }
+}
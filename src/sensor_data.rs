@@ -1,45 +1,54 @@
-use crate::types::{Sensor3DData, Sensor3DDataScaled};
+use core::f32::consts::PI;
 
-/// Standard gravity in m/s^2
-pub const GRAVITY: f32 = 9.8;
+use crate::types::{
+    AccelUnit, AccelerometerRange, GyroUnit, GyroscopeRange, ImuData, ImuDataScaled, Sensor3DData,
+    Sensor3DDataMilli, Sensor3DDataScaled, SENSITIVITY_FULL_SCALE_LSB,
+};
+
+/// Standard gravity in m/s^2, the default gravity of a [`crate::Bmi323`]
+pub const GRAVITY: f32 = 9.80665;
+/// Standard gravity in µm/s^2, for the integer conversions
+pub const GRAVITY_UM_PER_S2: i64 = 9_806_650;
+/// Raw value corresponding to the full scale of a range
+const FULL_SCALE_LSB: i64 = SENSITIVITY_FULL_SCALE_LSB as i64;
+/// Full scale of a range as a power of two, 2^15 LSB
+const FULL_SCALE_SHIFT: u32 = 15;
+/// Temperature sensitivity in LSB per degree Celsius
+pub const TEMPERATURE_LSB_PER_CELSIUS: f32 = 512.0;
+/// Temperature corresponding to a raw reading of zero, in degrees Celsius
+pub const TEMPERATURE_OFFSET_CELSIUS: f32 = 23.0;
 /// Alias for accelormeter data
 pub type AccelerometerData = Sensor3DData;
 /// Alias for gyroscope data
 pub type GyroscopeData = Sensor3DData;
 
 impl Sensor3DData {
-    /// Convert raw sensor data to scaled values
-    ///
-    /// # Arguments
-    ///
-    /// * `scale` - The full scale value
-    /// * `half_scale` - Half of the full scale value
-    fn to_scaled(&self, scale: f32, half_scale: f32) -> Sensor3DDataScaled {
+    /// Multiply each axis by `factor`
+    fn scale(self, factor: f32) -> Sensor3DDataScaled {
         Sensor3DDataScaled {
-            x: Self::lsb_to_scaled(self.x, scale, half_scale),
-            y: Self::lsb_to_scaled(self.y, scale, half_scale),
-            z: Self::lsb_to_scaled(self.z, scale, half_scale),
+            x: f32::from(self.x) * factor,
+            y: f32::from(self.y) * factor,
+            z: f32::from(self.z) * factor,
         }
     }
 
-    /// Convert raw LSB value to scaled value
+    /// Convert raw accelerometer data to m/s^2 using standard gravity
     ///
     /// # Arguments
     ///
-    /// * `val` - Raw LSB value
-    /// * `scale` - The full scale value
-    /// * `half_scale` - Half of the full scale value
-    fn lsb_to_scaled(val: i16, scale: f32, half_scale: f32) -> f32 {
-        (scale * val as f32) / half_scale
+    /// * `g` - The G-force value for the current range setting
+    pub fn to_mps2(&self, g: f32) -> Sensor3DDataScaled {
+        self.to_mps2_with_gravity(g, GRAVITY)
     }
 
-    /// Convert raw accelerometer data to m/s^2
+    /// Convert raw accelerometer data to m/s^2 using a local gravity
     ///
     /// # Arguments
     ///
     /// * `g` - The G-force value for the current range setting
-    pub fn to_mps2(&self, g: f32) -> Sensor3DDataScaled {
-        self.to_scaled(GRAVITY * g, f32::from(i16::MAX))
+    /// * `gravity` - The gravity in m/s^2
+    pub fn to_mps2_with_gravity(&self, g: f32, gravity: f32) -> Sensor3DDataScaled {
+        self.scale(g * gravity / SENSITIVITY_FULL_SCALE_LSB)
     }
 
     /// Convert raw gyroscope data to degrees per second
@@ -48,6 +57,213 @@ impl Sensor3DData {
     ///
     /// * `dps` - The degrees per second value for the current range setting
     pub fn to_dps(&self, dps: f32) -> Sensor3DDataScaled {
-        self.to_scaled(dps, f32::from(i16::MAX))
+        self.scale(dps / SENSITIVITY_FULL_SCALE_LSB)
+    }
+
+    /// Convert raw accelerometer data using the sensitivity of `range`
+    ///
+    /// m/s^2 are converted with standard gravity.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    /// * `unit` - The unit of the result
+    pub fn to_accel_units(&self, range: AccelerometerRange, unit: AccelUnit) -> Sensor3DDataScaled {
+        self.to_accel_units_with_gravity(range, unit, GRAVITY)
+    }
+
+    /// Convert raw accelerometer data using the sensitivity of `range` and
+    /// a local gravity
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    /// * `unit` - The unit of the result
+    /// * `gravity` - The gravity in m/s^2, only used for m/s^2
+    pub fn to_accel_units_with_gravity(
+        &self,
+        range: AccelerometerRange,
+        unit: AccelUnit,
+        gravity: f32,
+    ) -> Sensor3DDataScaled {
+        let g_per_lsb = 1.0 / range.sensitivity();
+        match unit {
+            AccelUnit::G => self.scale(g_per_lsb),
+            AccelUnit::MetersPerSecondSquared => self.scale(g_per_lsb * gravity),
+        }
+    }
+
+    /// Convert raw gyroscope data using the sensitivity of `range`
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The gyroscope range the data was measured with
+    /// * `unit` - The unit of the result
+    pub fn to_gyro_units(&self, range: GyroscopeRange, unit: GyroUnit) -> Sensor3DDataScaled {
+        let dps_per_lsb = 1.0 / range.sensitivity();
+        match unit {
+            GyroUnit::DegreesPerSecond => self.scale(dps_per_lsb),
+            GyroUnit::RadiansPerSecond => self.scale(dps_per_lsb * (PI / 180.0)),
+        }
+    }
+
+    /// Scale each axis by `full_scale / 2^15` in 32-bit integer arithmetic,
+    /// rounding to the nearest value
+    ///
+    /// The powers of two in `full_scale` are taken out of the shift, so the
+    /// product stays within `i32` for all ranges.
+    fn to_milli(self, full_scale: i32) -> Sensor3DDataMilli {
+        let twos = full_scale.trailing_zeros().min(FULL_SCALE_SHIFT - 1);
+        let factor = full_scale >> twos;
+        let shift = FULL_SCALE_SHIFT - twos;
+        let scale = |val: i16| mul_shift_round(i32::from(val), factor, shift);
+        Sensor3DDataMilli {
+            x: scale(self.x),
+            y: scale(self.y),
+            z: scale(self.z),
+        }
+    }
+
+    /// Convert raw accelerometer data to milli-g without floating point
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    pub fn to_milli_g(&self, range: AccelerometerRange) -> Sensor3DDataMilli {
+        self.to_milli(i32::from(range.full_scale_g()) * 1000)
+    }
+
+    /// Convert raw accelerometer data to mm/s^2 without floating point
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    pub fn to_mm_per_s2(&self, range: AccelerometerRange) -> Sensor3DDataMilli {
+        // The µm/s² product needs 64 bits
+        let numerator = i64::from(range.full_scale_g()) * GRAVITY_UM_PER_S2;
+        let scale = |val: i16| div_round(i64::from(val) * numerator, FULL_SCALE_LSB * 1000) as i32;
+        Sensor3DDataMilli {
+            x: scale(self.x),
+            y: scale(self.y),
+            z: scale(self.z),
+        }
+    }
+
+    /// Convert raw gyroscope data to milli-degrees per second without
+    /// floating point
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The gyroscope range the data was measured with
+    pub fn to_milli_dps(&self, range: GyroscopeRange) -> Sensor3DDataMilli {
+        self.to_milli(i32::from(range.full_scale_dps()) * 1000)
+    }
+}
+
+/// Multiply by `factor` and divide by 2^`shift`, rounding halfway cases
+/// away from zero
+fn mul_shift_round(value: i32, factor: i32, shift: u32) -> i32 {
+    let magnitude = (value.abs() * factor + (1 << (shift - 1))) >> shift;
+    if value < 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Divide, rounding halfway cases away from zero
+fn div_round(numerator: i64, denominator: i64) -> i64 {
+    let half = denominator / 2;
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+/// Convert a raw temperature reading to degrees Celsius
+///
+/// # Arguments
+///
+/// * `raw` - Raw value read from TEMP_DATA
+pub fn temperature_to_celsius(raw: i16) -> f32 {
+    f32::from(raw) / TEMPERATURE_LSB_PER_CELSIUS + TEMPERATURE_OFFSET_CELSIUS
+}
+
+/// Convert a raw temperature reading to hundredths of a degree Celsius
+/// without floating point
+///
+/// # Arguments
+///
+/// * `raw` - Raw value read from TEMP_DATA
+pub fn temperature_to_centi_celsius(raw: i16) -> i32 {
+    // 512 LSB per degree Celsius is a shift by 9
+    let lsb_per_celsius = TEMPERATURE_LSB_PER_CELSIUS as i32;
+    let offset = TEMPERATURE_OFFSET_CELSIUS as i32 * 100 * lsb_per_celsius;
+    mul_shift_round(
+        i32::from(raw) * 100 + offset,
+        1,
+        lsb_per_celsius.trailing_zeros(),
+    )
+}
+
+impl ImuData {
+    /// Convert a raw burst read to m/s^2 and degrees per second
+    ///
+    /// # Arguments
+    ///
+    /// * `accel_range` - The accelerometer range the data was measured with
+    /// * `gyro_range` - The gyroscope range the data was measured with
+    pub fn to_scaled(
+        self,
+        accel_range: AccelerometerRange,
+        gyro_range: GyroscopeRange,
+    ) -> ImuDataScaled {
+        self.to_scaled_in(
+            accel_range,
+            gyro_range,
+            AccelUnit::default(),
+            GyroUnit::default(),
+        )
+    }
+
+    /// Convert a raw burst read to scaled values in the given units
+    ///
+    /// m/s^2 are converted with standard gravity.
+    ///
+    /// # Arguments
+    ///
+    /// * `accel_range` - The accelerometer range the data was measured with
+    /// * `gyro_range` - The gyroscope range the data was measured with
+    /// * `accel_unit` - The unit of the accelerometer data
+    /// * `gyro_unit` - The unit of the gyroscope data
+    pub fn to_scaled_in(
+        self,
+        accel_range: AccelerometerRange,
+        gyro_range: GyroscopeRange,
+        accel_unit: AccelUnit,
+        gyro_unit: GyroUnit,
+    ) -> ImuDataScaled {
+        self.to_scaled_with_gravity(accel_range, gyro_range, accel_unit, gyro_unit, GRAVITY)
+    }
+
+    /// [`Self::to_scaled_in`] with a local gravity for m/s^2
+    pub(crate) fn to_scaled_with_gravity(
+        self,
+        accel_range: AccelerometerRange,
+        gyro_range: GyroscopeRange,
+        accel_unit: AccelUnit,
+        gyro_unit: GyroUnit,
+        gravity: f32,
+    ) -> ImuDataScaled {
+        ImuDataScaled {
+            accel: self
+                .accel
+                .to_accel_units_with_gravity(accel_range, accel_unit, gravity),
+            gyro: self.gyro.to_gyro_units(gyro_range, gyro_unit),
+            temperature: temperature_to_celsius(self.temperature),
+            sensor_time: self.sensor_time,
+            saturation: self.saturation,
+        }
     }
 }
@@ -0,0 +1,64 @@
+use core::time::Duration;
+
+/// Duration of one sensor time tick in nanoseconds, times two (39.0625 µs = 78125 / 2 ns)
+const TICK_NS_X2: u64 = 78_125;
+/// Number of sensor time ticks per second
+pub const SENSOR_TIME_TICKS_PER_SECOND: u64 = 25_600;
+
+/// Convert a number of sensor time ticks to a `Duration`
+///
+/// # Arguments
+///
+/// * `ticks` - Number of 39.0625 µs sensor time ticks
+pub fn ticks_to_duration(ticks: u64) -> Duration {
+    let secs = ticks / SENSOR_TIME_TICKS_PER_SECOND;
+    let nanos = (ticks % SENSOR_TIME_TICKS_PER_SECOND) * TICK_NS_X2 / 2;
+    Duration::new(secs, nanos as u32)
+}
+
+/// Extends the 32-bit SENSOR_TIME counter to a monotonic 64-bit tick count
+///
+/// The counter wraps roughly every 46.6 hours, so `update` has to be fed a
+/// reading at least once per wrap period for the extension to stay correct.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SensorClock {
+    /// Last raw counter value seen
+    last: u32,
+    /// Number of wraparounds observed so far
+    wraps: u32,
+}
+
+impl SensorClock {
+    /// Create a new clock starting at tick zero
+    pub const fn new() -> Self {
+        SensorClock { last: 0, wraps: 0 }
+    }
+
+    /// Feed a raw sensor time reading and return the extended tick count
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Raw value read from SENSOR_TIME
+    pub fn update(&mut self, raw: u32) -> u64 {
+        if raw < self.last {
+            self.wraps = self.wraps.wrapping_add(1);
+        }
+        self.last = raw;
+        self.ticks()
+    }
+
+    /// Extended tick count of the last reading
+    pub fn ticks(&self) -> u64 {
+        (u64::from(self.wraps) << 32) | u64::from(self.last)
+    }
+
+    /// Time elapsed since the counter started, as of the last reading
+    pub fn elapsed(&self) -> Duration {
+        ticks_to_duration(self.ticks())
+    }
+
+    /// Elapsed time in microseconds, as of the last reading
+    pub fn as_micros(&self) -> u64 {
+        self.ticks() * 625 / 16
+    }
+}
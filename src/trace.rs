@@ -0,0 +1,253 @@
+//! Bus transaction tracing
+//!
+//! [`Traced`] wraps any interface implementing [`ReadData`] and [`WriteData`]
+//! and reports every register access to a [`TraceSink`]. [`TraceRing`] keeps
+//! the most recent accesses in a fixed-size buffer, and any
+//! `FnMut(&TraceEvent)` closure can be used as a sink as well.
+//!
+//! With the `defmt` or `log` cargo feature enabled, every access is also
+//! emitted at trace level.
+
+use crate::{
+    interface::{ReadData, WriteData},
+    SpiMode,
+};
+
+/// Direction of a register access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Access {
+    /// Data read from the device
+    Read,
+    /// Data written to the device
+    Write,
+}
+
+/// A single register access as seen by [`Traced`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent<'a> {
+    /// Direction of the access
+    pub access: Access,
+    /// First register accessed
+    pub register: u8,
+    /// Data read or written, without dummy bytes
+    pub data: &'a [u8],
+    /// Whether the transport reported success
+    pub ok: bool,
+}
+
+/// Receiver of [`TraceEvent`]s
+pub trait TraceSink {
+    /// Record a register access
+    fn record(&mut self, event: &TraceEvent<'_>);
+}
+
+impl<F> TraceSink for F
+where
+    F: FnMut(&TraceEvent<'_>),
+{
+    fn record(&mut self, event: &TraceEvent<'_>) {
+        self(event)
+    }
+}
+
+/// Number of data bytes kept per [`TraceRecord`]
+pub const TRACE_RECORD_DATA_LEN: usize = 8;
+
+/// Owned copy of a [`TraceEvent`], as stored by [`TraceRing`]
+///
+/// Only the first [`TRACE_RECORD_DATA_LEN`] data bytes are kept; `len` holds
+/// the length of the original access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TraceRecord {
+    /// Direction of the access
+    pub access: Access,
+    /// First register accessed
+    pub register: u8,
+    /// Length of the access in bytes
+    pub len: usize,
+    /// Whether the transport reported success
+    pub ok: bool,
+    bytes: [u8; TRACE_RECORD_DATA_LEN],
+}
+
+impl TraceRecord {
+    /// The recorded data bytes, truncated to [`TRACE_RECORD_DATA_LEN`]
+    pub fn data(&self) -> &[u8] {
+        &self.bytes[..self.len.min(TRACE_RECORD_DATA_LEN)]
+    }
+}
+
+impl From<&TraceEvent<'_>> for TraceRecord {
+    fn from(event: &TraceEvent<'_>) -> Self {
+        let mut bytes = [0u8; TRACE_RECORD_DATA_LEN];
+        let kept = event.data.len().min(TRACE_RECORD_DATA_LEN);
+        bytes[..kept].copy_from_slice(&event.data[..kept]);
+        TraceRecord {
+            access: event.access,
+            register: event.register,
+            len: event.data.len(),
+            ok: event.ok,
+            bytes,
+        }
+    }
+}
+
+/// Ring buffer keeping the last `N` register accesses
+#[derive(Debug, Clone)]
+pub struct TraceRing<const N: usize> {
+    records: [Option<TraceRecord>; N],
+    next: usize,
+}
+
+impl<const N: usize> Default for TraceRing<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TraceRing<N> {
+    /// Create an empty ring buffer
+    pub const fn new() -> Self {
+        TraceRing {
+            records: [None; N],
+            next: 0,
+        }
+    }
+
+    /// Iterate over the recorded accesses, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &TraceRecord> {
+        let (newer, older) = self.records.split_at(self.next);
+        older.iter().chain(newer.iter()).flatten()
+    }
+
+    /// Number of recorded accesses
+    pub fn len(&self) -> usize {
+        self.records.iter().flatten().count()
+    }
+
+    /// Whether no access has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget all recorded accesses
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<const N: usize> TraceSink for TraceRing<N> {
+    fn record(&mut self, event: &TraceEvent<'_>) {
+        if N == 0 {
+            return;
+        }
+        self.records[self.next] = Some(TraceRecord::from(event));
+        self.next = (self.next + 1) % N;
+    }
+}
+
+/// Interface wrapper recording every register access
+///
+/// Works with [`I2cInterface`](crate::I2cInterface),
+/// [`SpiInterface`](crate::SpiInterface) and custom transports, including
+/// the dummy read and IO_SPI_IF write that switch the device to SPI in
+/// `init`.
+#[derive(Debug)]
+pub struct Traced<DI, S> {
+    inner: DI,
+    sink: S,
+}
+
+impl<DI, S> Traced<DI, S>
+where
+    S: TraceSink,
+{
+    /// Wrap an interface, sending its accesses to `sink`
+    pub fn new(inner: DI, sink: S) -> Self {
+        Traced { inner, sink }
+    }
+
+    /// Access the trace sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Mutably access the trace sink
+    pub fn sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    /// Mutably access the wrapped interface
+    pub fn inner_mut(&mut self) -> &mut DI {
+        &mut self.inner
+    }
+
+    /// Release the wrapped interface and the sink
+    pub fn into_parts(self) -> (DI, S) {
+        (self.inner, self.sink)
+    }
+
+    fn emit(&mut self, event: TraceEvent<'_>) {
+        #[cfg(feature = "defmt")]
+        defmt::trace!(
+            "bmi323 {} {=u8:#04x} {=[u8]:02x} ok={=bool}",
+            event.access,
+            event.register,
+            event.data,
+            event.ok
+        );
+        #[cfg(feature = "log")]
+        log::trace!(
+            "bmi323 {:?} {:#04x} {:02x?} ok={}",
+            event.access,
+            event.register,
+            event.data,
+            event.ok
+        );
+        self.sink.record(&event);
+    }
+}
+
+impl<DI, S> ReadData for Traced<DI, S>
+where
+    DI: ReadData,
+    S: TraceSink,
+{
+    type Error = DI::Error;
+
+    fn spi_mode(&self) -> Option<SpiMode> {
+        self.inner.spi_mode()
+    }
+
+    fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        let result = self.inner.read_data(register, data);
+        self.emit(TraceEvent {
+            access: Access::Read,
+            register,
+            data,
+            ok: result.is_ok(),
+        });
+        result
+    }
+}
+
+impl<DI, S> WriteData for Traced<DI, S>
+where
+    DI: WriteData,
+    S: TraceSink,
+{
+    type Error = DI::Error;
+
+    fn write_data(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error> {
+        let result = self.inner.write_data(register, data);
+        self.emit(TraceEvent {
+            access: Access::Write,
+            register,
+            data,
+            ok: result.is_ok(),
+        });
+        result
+    }
+}
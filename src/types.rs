@@ -1,16 +1,349 @@
-use core::fmt::Debug;
+use core::{convert::Infallible, fmt::Debug, time::Duration};
 
 /// Possible errors that can occur when interacting with the BMI323
+///
+/// `P` is the error type of the interrupt pin, for the methods waiting on
+/// one. Everything else can't fail on a pin and uses the default.
 #[derive(Debug)]
-pub enum Error<E> {
+pub enum Error<E, P = Infallible> {
     /// Communication error
     Comm(E),
     /// Invalid device (wrong chip ID)
     InvalidDevice,
+    /// The device reported a fatal error and needs to be reset
+    Fatal(DeviceErrors),
+    /// The feature engine is overloaded or its watchdog fired
+    FeatureEngine(DeviceErrors),
+    /// The device detected an I3C protocol error
+    I3c(DeviceErrors),
     /// Invalid configuration
-    InvalidConfig,
+    InvalidConfig(ConfigError),
     /// Timeout error
     Timeout,
+    /// The interrupt pin reported an error
+    Pin(P),
+}
+
+impl<E> Error<E> {
+    /// Convert to an error type with an interrupt pin error
+    pub fn with_pin_error<P>(self) -> Error<E, P> {
+        match self {
+            Error::Comm(e) => Error::Comm(e),
+            Error::InvalidDevice => Error::InvalidDevice,
+            Error::Fatal(errors) => Error::Fatal(errors),
+            Error::FeatureEngine(errors) => Error::FeatureEngine(errors),
+            Error::I3c(errors) => Error::I3c(errors),
+            Error::InvalidConfig(e) => Error::InvalidConfig(e),
+            Error::Timeout => Error::Timeout,
+            Error::Pin(never) => match never {},
+        }
+    }
+}
+
+/// Error conditions decoded from the ERR_REG register
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DeviceErrors {
+    /// Fatal error, the device needs a soft reset or power cycle
+    pub fatal: bool,
+    /// Feature engine overload
+    pub feature_engine_overload: bool,
+    /// Feature engine watchdog timeout
+    pub feature_engine_watchdog: bool,
+    /// Invalid accelerometer configuration
+    pub acc_conf: bool,
+    /// Invalid gyroscope configuration
+    pub gyr_conf: bool,
+    /// I3C error: SDR parity error or read/write address mismatch
+    pub i3c_error0: bool,
+    /// I3C error: S0/S1 error
+    pub i3c_error3: bool,
+}
+
+impl DeviceErrors {
+    /// Whether no error condition is set
+    pub fn is_empty(&self) -> bool {
+        *self == DeviceErrors::default()
+    }
+
+    /// Whether the feature engine is overloaded or its watchdog fired
+    pub fn feature_engine(&self) -> bool {
+        self.feature_engine_overload || self.feature_engine_watchdog
+    }
+
+    /// Whether either I3C error flag is set
+    pub fn i3c(&self) -> bool {
+        self.i3c_error0 || self.i3c_error3
+    }
+
+    /// The sensor whose configuration was rejected, the accelerometer if both
+    pub fn rejected_sensor(&self) -> Option<SensorType> {
+        if self.acc_conf {
+            Some(SensorType::Accelerometer)
+        } else if self.gyr_conf {
+            Some(SensorType::Gyroscope)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<u16> for DeviceErrors {
+    /// Decode an ERR_REG register value
+    fn from(value: u16) -> Self {
+        DeviceErrors {
+            fatal: (value & 0x0001) != 0,
+            feature_engine_overload: (value & 0x0004) != 0,
+            feature_engine_watchdog: (value & 0x0010) != 0,
+            acc_conf: (value & 0x0020) != 0,
+            gyr_conf: (value & 0x0040) != 0,
+            i3c_error0: (value & 0x0100) != 0,
+            i3c_error3: (value & 0x0800) != 0,
+        }
+    }
+}
+
+/// Reasons a sensor configuration is rejected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfigError {
+    /// Reserved output data rate code
+    InvalidOdr,
+    /// Reserved range code
+    InvalidRange,
+    /// Reserved averaging code
+    InvalidAvgNum,
+    /// Reserved power mode code
+    InvalidMode,
+    /// Output data rate not supported in the selected power mode
+    OdrNotSupported,
+    /// Averaging is only available in low power mode
+    AveragingNotSupported,
+    /// Averaged samples do not fit in one output data rate period
+    AveragingTooLong,
+    /// The device flagged the configuration of this sensor as invalid
+    Rejected(SensorType),
+    /// Pad drive strength above the highest level
+    InvalidDriveStrength,
+    /// FIFO watermark above the FIFO size
+    InvalidFifoWatermark,
+    /// A sensor the operation needs is turned off
+    SensorDisabled,
+    /// Accelerometer and gyroscope run at different output data rates
+    OdrMismatch,
+}
+
+/// I2C watchdog setting (IO_I2C_IF)
+///
+/// When enabled, the device releases SDA if SCL has not toggled within the
+/// timeout, e.g. after the host was reset in the middle of a read.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum I2cWatchdog {
+    /// Watchdog disabled
+    #[default]
+    Disabled,
+    /// Release the bus after 1.25 ms
+    Timeout1_25ms,
+    /// Release the bus after 40 ms
+    Timeout40ms,
+}
+
+impl From<I2cWatchdog> for u16 {
+    /// Convert to the watchdog_sel (bit 0) and watchdog_en (bit 1) fields
+    fn from(watchdog: I2cWatchdog) -> Self {
+        match watchdog {
+            I2cWatchdog::Disabled => 0b00,
+            I2cWatchdog::Timeout1_25ms => 0b10,
+            I2cWatchdog::Timeout40ms => 0b11,
+        }
+    }
+}
+
+impl From<u16> for I2cWatchdog {
+    /// Decode an IO_I2C_IF register value
+    fn from(value: u16) -> Self {
+        match value & 0b11 {
+            0b10 => I2cWatchdog::Timeout1_25ms,
+            0b11 => I2cWatchdog::Timeout40ms,
+            _ => I2cWatchdog::Disabled,
+        }
+    }
+}
+
+/// Output drive strength of the interface pads (IO_PAD_STRENGTH)
+///
+/// The if_drv level ranges from 0 (weakest) to 7 (strongest). The i2c_boost
+/// bit additionally strengthens the SDA pad in I2C mode, e.g. for buses with
+/// a large capacitance.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DriveStrength {
+    level: u8,
+    i2c_boost: bool,
+}
+
+impl DriveStrength {
+    /// Weakest drive strength
+    pub const MIN: DriveStrength = DriveStrength {
+        level: 0,
+        i2c_boost: false,
+    };
+    /// Highest drive strength level, without I2C boost
+    pub const MAX: DriveStrength = DriveStrength {
+        level: 7,
+        i2c_boost: false,
+    };
+
+    /// Enable or disable the I2C boost
+    pub fn with_i2c_boost(mut self, i2c_boost: bool) -> Self {
+        self.i2c_boost = i2c_boost;
+        self
+    }
+
+    /// The drive strength level, 0 to 7
+    pub fn level(self) -> u8 {
+        self.level
+    }
+
+    /// Whether the I2C boost is enabled
+    pub fn i2c_boost(self) -> bool {
+        self.i2c_boost
+    }
+
+    /// Decode the if_drv and i2c_boost fields of an IO_PAD_STRENGTH register value
+    pub(crate) fn from_register(value: u16) -> Self {
+        DriveStrength {
+            level: (value & 0b111) as u8,
+            i2c_boost: value & 0b1000 != 0,
+        }
+    }
+}
+
+impl From<DriveStrength> for u16 {
+    /// Convert to the if_drv (bits 0-2) and i2c_boost (bit 3) fields
+    fn from(strength: DriveStrength) -> Self {
+        u16::from(strength.level) | (u16::from(strength.i2c_boost) << 3)
+    }
+}
+
+impl TryFrom<u8> for DriveStrength {
+    type Error = ConfigError;
+
+    /// Create a drive strength level without I2C boost
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value <= Self::MAX.level {
+            Ok(DriveStrength {
+                level: value,
+                i2c_boost: false,
+            })
+        } else {
+            Err(ConfigError::InvalidDriveStrength)
+        }
+    }
+}
+
+/// I2C slave address, selected by the level of the SDO pin
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SlaveAddress {
+    /// SDO tied to GND
+    #[default]
+    SdoLow = 0x68,
+    /// SDO tied to VDDIO
+    SdoHigh = 0x69,
+}
+
+impl From<SlaveAddress> for u8 {
+    fn from(address: SlaveAddress) -> Self {
+        address as u8
+    }
+}
+
+/// SPI wiring mode
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SpiMode {
+    /// Separate SDI and SDO lines
+    #[default]
+    FourWire = 0,
+    /// Bidirectional data on SDx, SDO unused
+    ThreeWire = 1,
+}
+
+/// Interrupt output pin
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InterruptPin {
+    /// INT1 pin
+    #[default]
+    Int1 = 1,
+    /// INT2 pin
+    Int2 = 2,
+}
+
+/// Interrupt sources that can be routed to a pin through INT_MAP2
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterruptSource {
+    /// New gyroscope sample
+    GyroDataReady = 8,
+    /// New accelerometer sample
+    AccelDataReady = 10,
+    /// FIFO fill level reached the watermark
+    FifoWatermark = 12,
+}
+
+/// FIFO settings (FIFO_CONF and FIFO_WATERMARK)
+///
+/// Each FIFO frame holds the enabled outputs in the order accelerometer,
+/// gyroscope, temperature, sensor time, one 16-bit word per value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FifoConfig {
+    /// Store accelerometer data
+    pub accel: bool,
+    /// Store gyroscope data
+    pub gyro: bool,
+    /// Store temperature data
+    pub temperature: bool,
+    /// Store the sensor time
+    pub sensor_time: bool,
+    /// Stop writing when full instead of overwriting the oldest frames
+    pub stop_on_full: bool,
+    /// Fill level triggering [`InterruptSource::FifoWatermark`], in words
+    ///
+    /// At most [`FifoConfig::MAX_WATERMARK`].
+    pub watermark_words: u16,
+}
+
+impl FifoConfig {
+    /// Highest watermark the FIFO_WATERMARK field can hold, in words
+    pub const MAX_WATERMARK: u16 = 0x03FF;
+
+    /// Check the watermark against the FIFO_WATERMARK field
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.watermark_words > Self::MAX_WATERMARK {
+            return Err(ConfigError::InvalidFifoWatermark);
+        }
+        Ok(())
+    }
+}
+
+impl Default for FifoConfig {
+    /// Accelerometer and gyroscope frames, watermark at 64 words
+    fn default() -> Self {
+        FifoConfig {
+            accel: true,
+            gyro: true,
+            temperature: false,
+            sensor_time: false,
+            stop_on_full: false,
+            watermark_words: 64,
+        }
+    }
+}
+
+impl From<FifoConfig> for u16 {
+    /// Convert to a FIFO_CONF register value
+    fn from(config: FifoConfig) -> Self {
+        u16::from(config.stop_on_full)
+            | (u16::from(config.sensor_time) << 8)
+            | (u16::from(config.accel) << 9)
+            | (u16::from(config.gyro) << 10)
+            | (u16::from(config.temperature) << 11)
+    }
 }
 
 /// Accelerometer power modes
@@ -26,28 +359,81 @@ pub enum AccelerometerPowerMode {
     HighPerf = 0x07,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+impl TryFrom<u8> for AccelerometerPowerMode {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(AccelerometerPowerMode::Disable),
+            0x03 => Ok(AccelerometerPowerMode::LowPower),
+            0x04 => Ok(AccelerometerPowerMode::Normal),
+            0x07 => Ok(AccelerometerPowerMode::HighPerf),
+            _ => Err(ConfigError::InvalidMode),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum AccelerometerRange {
     G2 = 0,
     G4 = 1,
+    #[default]
     G8 = 2,
     G16 = 3,
 }
 
+impl TryFrom<u8> for AccelerometerRange {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AccelerometerRange::G2),
+            1 => Ok(AccelerometerRange::G4),
+            2 => Ok(AccelerometerRange::G8),
+            3 => Ok(AccelerometerRange::G16),
+            _ => Err(ConfigError::InvalidRange),
+        }
+    }
+}
+
 impl AccelerometerRange {
     pub fn to_g(self) -> f32 {
+        f32::from(self.full_scale_g())
+    }
+
+    /// Datasheet sensitivity in LSB per g, e.g. 4096 at ±8 g
+    pub fn sensitivity(self) -> f32 {
+        SENSITIVITY_FULL_SCALE_LSB / self.to_g()
+    }
+
+    /// Full scale of the range in g, as an integer
+    pub fn full_scale_g(self) -> u16 {
         match self {
-            AccelerometerRange::G2 => 2.0,
-            AccelerometerRange::G4 => 4.0,
-            AccelerometerRange::G8 => 8.0,
-            AccelerometerRange::G16 => 16.0,
+            AccelerometerRange::G2 => 2,
+            AccelerometerRange::G4 => 4,
+            AccelerometerRange::G8 => 8,
+            AccelerometerRange::G16 => 16,
         }
     }
-}
 
-impl Default for AccelerometerRange {
-    fn default() -> Self {
-        AccelerometerRange::G8
+    /// The next larger range, if any
+    pub fn next_larger(self) -> Option<Self> {
+        match self {
+            AccelerometerRange::G2 => Some(AccelerometerRange::G4),
+            AccelerometerRange::G4 => Some(AccelerometerRange::G8),
+            AccelerometerRange::G8 => Some(AccelerometerRange::G16),
+            AccelerometerRange::G16 => None,
+        }
+    }
+
+    /// The next smaller range, if any
+    pub fn next_smaller(self) -> Option<Self> {
+        match self {
+            AccelerometerRange::G2 => None,
+            AccelerometerRange::G4 => Some(AccelerometerRange::G2),
+            AccelerometerRange::G8 => Some(AccelerometerRange::G4),
+            AccelerometerRange::G16 => Some(AccelerometerRange::G8),
+        }
     }
 }
 
@@ -66,8 +452,23 @@ pub enum GyroscopePowerMode {
     HighPerf = 0x07,
 }
 
+impl TryFrom<u8> for GyroscopePowerMode {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(GyroscopePowerMode::Disable),
+            0x01 => Ok(GyroscopePowerMode::Suspend),
+            0x03 => Ok(GyroscopePowerMode::LowPower),
+            0x04 => Ok(GyroscopePowerMode::Normal),
+            0x07 => Ok(GyroscopePowerMode::HighPerf),
+            _ => Err(ConfigError::InvalidMode),
+        }
+    }
+}
+
 /// Gyroscope measurement ranges
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum GyroscopeRange {
     /// ±125 degrees per second
     DPS125 = 0,
@@ -78,24 +479,66 @@ pub enum GyroscopeRange {
     /// ±1000 degrees per second
     DPS1000 = 3,
     /// ±2000 degrees per second
+    #[default]
     DPS2000 = 4,
 }
 
+impl TryFrom<u8> for GyroscopeRange {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(GyroscopeRange::DPS125),
+            1 => Ok(GyroscopeRange::DPS250),
+            2 => Ok(GyroscopeRange::DPS500),
+            3 => Ok(GyroscopeRange::DPS1000),
+            4 => Ok(GyroscopeRange::DPS2000),
+            _ => Err(ConfigError::InvalidRange),
+        }
+    }
+}
+
 impl GyroscopeRange {
     pub fn to_dps(self) -> f32 {
+        f32::from(self.full_scale_dps())
+    }
+
+    /// Datasheet sensitivity in LSB per °/s, e.g. 16.384 at ±2000 °/s
+    pub fn sensitivity(self) -> f32 {
+        SENSITIVITY_FULL_SCALE_LSB / self.to_dps()
+    }
+
+    /// Full scale of the range in degrees per second, as an integer
+    pub fn full_scale_dps(self) -> u16 {
         match self {
-            GyroscopeRange::DPS125 => 125.0,
-            GyroscopeRange::DPS250 => 250.0,
-            GyroscopeRange::DPS500 => 500.0,
-            GyroscopeRange::DPS1000 => 1000.0,
-            GyroscopeRange::DPS2000 => 2000.0,
+            GyroscopeRange::DPS125 => 125,
+            GyroscopeRange::DPS250 => 250,
+            GyroscopeRange::DPS500 => 500,
+            GyroscopeRange::DPS1000 => 1000,
+            GyroscopeRange::DPS2000 => 2000,
         }
     }
-}
 
-impl Default for GyroscopeRange {
-    fn default() -> Self {
-        GyroscopeRange::DPS2000
+    /// The next larger range, if any
+    pub fn next_larger(self) -> Option<Self> {
+        match self {
+            GyroscopeRange::DPS125 => Some(GyroscopeRange::DPS250),
+            GyroscopeRange::DPS250 => Some(GyroscopeRange::DPS500),
+            GyroscopeRange::DPS500 => Some(GyroscopeRange::DPS1000),
+            GyroscopeRange::DPS1000 => Some(GyroscopeRange::DPS2000),
+            GyroscopeRange::DPS2000 => None,
+        }
+    }
+
+    /// The next smaller range, if any
+    pub fn next_smaller(self) -> Option<Self> {
+        match self {
+            GyroscopeRange::DPS125 => None,
+            GyroscopeRange::DPS250 => Some(GyroscopeRange::DPS125),
+            GyroscopeRange::DPS500 => Some(GyroscopeRange::DPS250),
+            GyroscopeRange::DPS1000 => Some(GyroscopeRange::DPS500),
+            GyroscopeRange::DPS2000 => Some(GyroscopeRange::DPS1000),
+        }
     }
 }
 
@@ -121,6 +564,116 @@ pub struct Sensor3DDataScaled {
     pub z: f32,
 }
 
+/// Raw value corresponding to the full scale of every range
+///
+/// All sensitivities in the datasheet are 2^15 LSB divided by the full scale.
+pub(crate) const SENSITIVITY_FULL_SCALE_LSB: f32 = 32768.0;
+
+/// Unit of scaled accelerometer data
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AccelUnit {
+    /// Standard gravity, g
+    G,
+    /// Meters per second squared
+    #[default]
+    MetersPerSecondSquared,
+}
+
+/// Unit of scaled gyroscope data
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GyroUnit {
+    /// Degrees per second
+    #[default]
+    DegreesPerSecond,
+    /// Radians per second
+    RadiansPerSecond,
+}
+
+/// 3D sensor data in integer milli-units (milli-g, mm/s^2 or milli-dps)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sensor3DDataMilli {
+    /// X-axis value
+    pub x: i32,
+    /// Y-axis value
+    pub y: i32,
+    /// Z-axis value
+    pub z: i32,
+}
+
+/// Per-axis saturation flags decoded from the SAT_FLAGS register
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SaturationFlags {
+    /// Accelerometer X-axis saturated
+    pub acc_x: bool,
+    /// Accelerometer Y-axis saturated
+    pub acc_y: bool,
+    /// Accelerometer Z-axis saturated
+    pub acc_z: bool,
+    /// Gyroscope X-axis saturated
+    pub gyr_x: bool,
+    /// Gyroscope Y-axis saturated
+    pub gyr_y: bool,
+    /// Gyroscope Z-axis saturated
+    pub gyr_z: bool,
+}
+
+impl SaturationFlags {
+    /// Whether any accelerometer axis is saturated
+    pub fn accel(&self) -> bool {
+        self.acc_x || self.acc_y || self.acc_z
+    }
+
+    /// Whether any gyroscope axis is saturated
+    pub fn gyro(&self) -> bool {
+        self.gyr_x || self.gyr_y || self.gyr_z
+    }
+}
+
+impl From<u16> for SaturationFlags {
+    /// Decode a SAT_FLAGS register value
+    fn from(value: u16) -> Self {
+        SaturationFlags {
+            acc_x: (value & 0b0000_0001) != 0,
+            acc_y: (value & 0b0000_0010) != 0,
+            acc_z: (value & 0b0000_0100) != 0,
+            gyr_x: (value & 0b0000_1000) != 0,
+            gyr_y: (value & 0b0001_0000) != 0,
+            gyr_z: (value & 0b0010_0000) != 0,
+        }
+    }
+}
+
+/// Raw data from a single burst read of all sensor output registers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuData {
+    /// Accelerometer data
+    pub accel: Sensor3DData,
+    /// Gyroscope data
+    pub gyro: Sensor3DData,
+    /// Raw temperature value
+    pub temperature: i16,
+    /// Sensor time at the moment of the read
+    pub sensor_time: u32,
+    /// Saturation flags
+    pub saturation: SaturationFlags,
+}
+
+/// Scaled data from a single burst read of all sensor output registers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuDataScaled {
+    /// Accelerometer data, in m/s^2 unless another [`AccelUnit`] was chosen
+    pub accel: Sensor3DDataScaled,
+    /// Gyroscope data, in degrees per second unless another [`GyroUnit`] was
+    /// chosen
+    pub gyro: Sensor3DDataScaled,
+    /// Temperature in degrees Celsius
+    pub temperature: f32,
+    /// Sensor time at the moment of the read
+    pub sensor_time: u32,
+    /// Saturation flags
+    pub saturation: SaturationFlags,
+}
+
 /// Output data rates for sensors
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OutputDataRate {
@@ -154,6 +707,43 @@ pub enum OutputDataRate {
     Odr6400hz = 0x0E,
 }
 
+impl TryFrom<u8> for OutputDataRate {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x01 => Ok(OutputDataRate::Odr0_78hz),
+            0x02 => Ok(OutputDataRate::Odr1_56hz),
+            0x03 => Ok(OutputDataRate::Odr3_125hz),
+            0x04 => Ok(OutputDataRate::Odr6_25hz),
+            0x05 => Ok(OutputDataRate::Odr12_5hz),
+            0x06 => Ok(OutputDataRate::Odr25hz),
+            0x07 => Ok(OutputDataRate::Odr50hz),
+            0x08 => Ok(OutputDataRate::Odr100hz),
+            0x09 => Ok(OutputDataRate::Odr200hz),
+            0x0A => Ok(OutputDataRate::Odr400hz),
+            0x0B => Ok(OutputDataRate::Odr800hz),
+            0x0C => Ok(OutputDataRate::Odr1600hz),
+            0x0D => Ok(OutputDataRate::Odr3200hz),
+            0x0E => Ok(OutputDataRate::Odr6400hz),
+            _ => Err(ConfigError::InvalidOdr),
+        }
+    }
+}
+
+impl OutputDataRate {
+    /// Number of 6.4 kHz base periods per output sample, as a power of two
+    pub(crate) fn base_periods_log2(self) -> u8 {
+        OutputDataRate::Odr6400hz as u8 - self as u8
+    }
+
+    /// Time between two samples
+    pub fn period(self) -> Duration {
+        // One 6.4 kHz base period is 156.25 µs
+        Duration::from_nanos(156_250 << self.base_periods_log2())
+    }
+}
+
 /// Number of samples to average
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AverageNum {
@@ -173,6 +763,23 @@ pub enum AverageNum {
     Avg64 = 0x06,
 }
 
+impl TryFrom<u8> for AverageNum {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(AverageNum::Avg1),
+            0x01 => Ok(AverageNum::Avg2),
+            0x02 => Ok(AverageNum::Avg4),
+            0x03 => Ok(AverageNum::Avg8),
+            0x04 => Ok(AverageNum::Avg16),
+            0x05 => Ok(AverageNum::Avg32),
+            0x06 => Ok(AverageNum::Avg64),
+            _ => Err(ConfigError::InvalidAvgNum),
+        }
+    }
+}
+
 /// Sensor bandwidth settings
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Bandwidth {
@@ -182,8 +789,22 @@ pub enum Bandwidth {
     OdrQuarter = 1,
 }
 
+impl From<bool> for Bandwidth {
+    /// Decode the single bandwidth bit
+    fn from(value: bool) -> Self {
+        if value {
+            Bandwidth::OdrQuarter
+        } else {
+            Bandwidth::OdrHalf
+        }
+    }
+}
+
+/// One of the two sensors of the device
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SensorType {
+    /// Accelerometer
     Accelerometer,
+    /// Gyroscope
     Gyroscope,
 }
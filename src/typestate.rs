@@ -0,0 +1,252 @@
+//! Typestate layer over [`Bmi323`]
+//!
+//! [`TypedBmi323`] tracks in its type whether the device has been initialized
+//! and which sensors are enabled, so that reading a sensor before `init` or
+//! before configuring it fails to compile:
+//!
+//! ```ignore
+//! let imu = TypedBmi323::new(Bmi323::new_with_i2c(i2c, SlaveAddress::SdoLow, delay));
+//! let imu = imu.init().map_err(|(_, e)| e)?;
+//! let mut imu = imu.enable_accel(AccelConfig::builder().build()).map_err(|(_, e)| e)?;
+//! let accel = imu.read_accel_data_scaled()?;
+//! // imu.read_gyro_data()?; // does not compile, the gyroscope is off
+//! ```
+//!
+//! Transitions consume the driver. On failure they hand it back together
+//! with the error, in its previous state. [`TypedBmi323::into_inner`] returns
+//! the untyped driver at any time.
+
+use core::marker::PhantomData;
+
+use crate::{
+    interface::{ReadData, WriteData},
+    AccelConfig, AccelerometerPowerMode, Bmi323, ConfigError, DeviceErrors, Error, GyroConfig,
+    GyroscopePowerMode, ImuData, ImuDataScaled, Sensor3DData, Sensor3DDataScaled,
+};
+use embedded_hal::delay::DelayNs;
+
+/// The device has not been initialized yet
+#[derive(Debug)]
+pub struct Uninit;
+
+/// The device has been initialized
+///
+/// `A` and `G` track the accelerometer and gyroscope state, either
+/// [`Enabled`] or [`Disabled`].
+#[derive(Debug)]
+pub struct Ready<A, G>(PhantomData<(A, G)>);
+
+/// The sensor produces data
+#[derive(Debug)]
+pub struct Enabled;
+
+/// The sensor is turned off
+#[derive(Debug)]
+pub struct Disabled;
+
+/// Result of a state transition, handing the driver back on failure
+pub type Transition<T, S, E> = Result<T, (S, Error<E>)>;
+
+/// [`Bmi323`] wrapper tracking initialization and sensor state in its type
+///
+/// Reading before `init` does not compile:
+///
+/// ```compile_fail
+/// # use bmi323::{Bmi323, SlaveAddress};
+/// # use embedded_hal_mock::eh1::{delay::NoopDelay, i2c::Mock};
+/// let i2c = Mock::new(&[]);
+/// let mut imu = Bmi323::new_with_i2c(i2c, SlaveAddress::SdoLow, NoopDelay::new()).into_typed();
+/// imu.read_accel_data();
+/// ```
+pub struct TypedBmi323<DI, D, S> {
+    inner: Bmi323<DI, D>,
+    _state: PhantomData<S>,
+}
+
+impl<DI, D, S> TypedBmi323<DI, D, S> {
+    fn into_state<T>(self) -> TypedBmi323<DI, D, T> {
+        TypedBmi323 {
+            inner: self.inner,
+            _state: PhantomData,
+        }
+    }
+
+    /// Return the untyped driver
+    pub fn into_inner(self) -> Bmi323<DI, D> {
+        self.inner
+    }
+}
+
+impl<DI, D> TypedBmi323<DI, D, Uninit> {
+    /// Wrap a driver that has not been initialized yet
+    pub fn new(inner: Bmi323<DI, D>) -> Self {
+        TypedBmi323 {
+            inner,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<DI, D, E> TypedBmi323<DI, D, Uninit>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Initialize the device, see [`Bmi323::init`]
+    ///
+    /// Both sensors are disabled after the soft reset.
+    pub fn init(mut self) -> Transition<TypedBmi323<DI, D, Ready<Disabled, Disabled>>, Self, E> {
+        match self.inner.init() {
+            Ok(()) => Ok(self.into_state()),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
+
+impl<DI, D, E, A, G> TypedBmi323<DI, D, Ready<A, G>>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Configure and enable the accelerometer
+    ///
+    /// Configurations with [`AccelerometerPowerMode::Disable`] are rejected
+    /// with [`ConfigError::InvalidMode`]; use [`Self::disable_accel`].
+    pub fn enable_accel(
+        mut self,
+        config: AccelConfig,
+    ) -> Transition<TypedBmi323<DI, D, Ready<Enabled, G>>, Self, E> {
+        if !config.is_enabled() {
+            return Err((self, Error::InvalidConfig(ConfigError::InvalidMode)));
+        }
+        match self.inner.set_accel_config(config) {
+            Ok(()) => Ok(self.into_state()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Configure and enable the gyroscope
+    ///
+    /// Configurations with [`GyroscopePowerMode::Disable`] or
+    /// [`GyroscopePowerMode::Suspend`] are rejected with
+    /// [`ConfigError::InvalidMode`]; use [`Self::disable_gyro`].
+    pub fn enable_gyro(
+        mut self,
+        config: GyroConfig,
+    ) -> Transition<TypedBmi323<DI, D, Ready<A, Enabled>>, Self, E> {
+        if !config.is_enabled() {
+            return Err((self, Error::InvalidConfig(ConfigError::InvalidMode)));
+        }
+        match self.inner.set_gyro_config(config) {
+            Ok(()) => Ok(self.into_state()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Turn the accelerometer off
+    pub fn disable_accel(mut self) -> Transition<TypedBmi323<DI, D, Ready<Disabled, G>>, Self, E> {
+        let config = AccelConfig::builder()
+            .mode(AccelerometerPowerMode::Disable)
+            .build();
+        match self.inner.set_accel_config(config) {
+            Ok(()) => Ok(self.into_state()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Turn the gyroscope off
+    pub fn disable_gyro(mut self) -> Transition<TypedBmi323<DI, D, Ready<A, Disabled>>, Self, E> {
+        let config = GyroConfig::builder()
+            .mode(GyroscopePowerMode::Disable)
+            .build();
+        match self.inner.set_gyro_config(config) {
+            Ok(()) => Ok(self.into_state()),
+            Err(e) => Err((self, e)),
+        }
+    }
+
+    /// Read and decode the error register, see [`Bmi323::read_errors`]
+    pub fn read_errors(&mut self) -> Result<DeviceErrors, Error<E>> {
+        self.inner.read_errors()
+    }
+
+    /// Check the error register, see [`Bmi323::check_errors`]
+    pub fn check_errors(&mut self) -> Result<(), Error<E>> {
+        self.inner.check_errors()
+    }
+
+    /// Read the raw 32-bit sensor time counter
+    pub fn read_sensor_time(&mut self) -> Result<u32, Error<E>> {
+        self.inner.read_sensor_time()
+    }
+}
+
+impl<DI, D, E, G> TypedBmi323<DI, D, Ready<Enabled, G>>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Read raw accelerometer data
+    pub fn read_accel_data(&mut self) -> Result<Sensor3DData, Error<E>> {
+        self.inner.read_accel_data()
+    }
+
+    /// Read scaled accelerometer data, see [`Bmi323::read_accel_data_scaled`]
+    pub fn read_accel_data_scaled(&mut self) -> Result<Sensor3DDataScaled, Error<E>> {
+        self.inner.read_accel_data_scaled()
+    }
+
+    /// Read the accelerometer as an [`Acceleration`](crate::Acceleration)
+    #[cfg(feature = "units")]
+    pub fn read_accel(&mut self) -> Result<crate::Acceleration, Error<E>> {
+        self.inner.read_accel()
+    }
+}
+
+impl<DI, D, E, A> TypedBmi323<DI, D, Ready<A, Enabled>>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Read raw gyroscope data
+    pub fn read_gyro_data(&mut self) -> Result<Sensor3DData, Error<E>> {
+        self.inner.read_gyro_data()
+    }
+
+    /// Read scaled gyroscope data, see [`Bmi323::read_gyro_data_scaled`]
+    pub fn read_gyro_data_scaled(&mut self) -> Result<Sensor3DDataScaled, Error<E>> {
+        self.inner.read_gyro_data_scaled()
+    }
+
+    /// Read the gyroscope as an [`AngularRate`](crate::AngularRate)
+    #[cfg(feature = "units")]
+    pub fn read_gyro(&mut self) -> Result<crate::AngularRate, Error<E>> {
+        self.inner.read_gyro()
+    }
+}
+
+impl<DI, D, E> TypedBmi323<DI, D, Ready<Enabled, Enabled>>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Read both sensors, temperature, sensor time and saturation flags
+    pub fn read_all(&mut self) -> Result<ImuData, Error<E>> {
+        self.inner.read_all()
+    }
+
+    /// Read both sensors, temperature, sensor time and saturation flags, scaled
+    pub fn read_all_scaled(&mut self) -> Result<ImuDataScaled, Error<E>> {
+        self.inner.read_all_scaled()
+    }
+}
+
+impl<DI, D> Bmi323<DI, D> {
+    /// Wrap the driver in the typestate layer
+    ///
+    /// The driver is treated as uninitialized, so `init` has to be called
+    /// through the wrapper before any read.
+    pub fn into_typed(self) -> TypedBmi323<DI, D, Uninit> {
+        TypedBmi323::new(self)
+    }
+}
@@ -0,0 +1,197 @@
+//! Typed physical units for sensor outputs
+//!
+//! Enabled with the `units` feature. [`Acceleration`] and [`AngularRate`]
+//! keep their unit in the type, so mixing up the two sensors, or degrees and
+//! radians per second, is a compile error instead of a wrong number. The
+//! plain values are only available through a method naming the unit:
+//!
+//! ```ignore
+//! let rate = imu.read_gyro()?;
+//! let [x, y, z] = rate.rad_per_s();
+//! ```
+
+use core::f32::consts::PI;
+
+use crate::{
+    interface::{ReadData, WriteData},
+    AccelUnit, AccelerometerRange, Bmi323, Error, GyroUnit, GyroscopeRange, Sensor3DData,
+    Sensor3DDataScaled, GRAVITY,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Multiply each axis by `factor`
+fn scale(v: [f32; 3], factor: f32) -> [f32; 3] {
+    v.map(|axis| axis * factor)
+}
+
+/// Acceleration along the x, y and z axes
+///
+/// Converts between m/s^2 and g with the gravity it was created with,
+/// standard gravity unless given.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acceleration {
+    /// Axes in m/s^2
+    mps2: [f32; 3],
+    /// Gravity in m/s^2
+    gravity: f32,
+}
+
+impl Default for Acceleration {
+    fn default() -> Self {
+        Self::from_mps2([0.0; 3])
+    }
+}
+
+impl Acceleration {
+    /// Create an acceleration from x, y and z in m/s^2
+    pub fn from_mps2(mps2: [f32; 3]) -> Self {
+        Acceleration {
+            mps2,
+            gravity: GRAVITY,
+        }
+    }
+
+    /// Create an acceleration from x, y and z in g
+    pub fn from_g(g: [f32; 3]) -> Self {
+        Self::from_g_with_gravity(g, GRAVITY)
+    }
+
+    /// Create an acceleration from x, y and z in g at a local gravity
+    ///
+    /// # Arguments
+    ///
+    /// * `g` - The axes in g
+    /// * `gravity` - The gravity in m/s^2
+    pub fn from_g_with_gravity(g: [f32; 3], gravity: f32) -> Self {
+        Acceleration {
+            mps2: scale(g, gravity),
+            gravity,
+        }
+    }
+
+    /// X, y and z in m/s^2
+    pub fn mps2(&self) -> [f32; 3] {
+        self.mps2
+    }
+
+    /// X, y and z in g
+    pub fn g(&self) -> [f32; 3] {
+        scale(self.mps2, 1.0 / self.gravity)
+    }
+
+    /// Gravity used to convert between m/s^2 and g
+    pub fn gravity(&self) -> f32 {
+        self.gravity
+    }
+
+    /// The axes in `unit`, for code working with [`Sensor3DDataScaled`]
+    pub fn to_scaled(&self, unit: AccelUnit) -> Sensor3DDataScaled {
+        let [x, y, z] = match unit {
+            AccelUnit::G => self.g(),
+            AccelUnit::MetersPerSecondSquared => self.mps2(),
+        };
+        Sensor3DDataScaled { x, y, z }
+    }
+}
+
+/// Angular rate around the x, y and z axes
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AngularRate {
+    /// Axes in degrees per second
+    dps: [f32; 3],
+}
+
+impl AngularRate {
+    /// Create an angular rate from x, y and z in degrees per second
+    pub fn from_dps(dps: [f32; 3]) -> Self {
+        AngularRate { dps }
+    }
+
+    /// Create an angular rate from x, y and z in radians per second
+    pub fn from_rad_per_s(rad_per_s: [f32; 3]) -> Self {
+        Self::from_dps(scale(rad_per_s, 180.0 / PI))
+    }
+
+    /// X, y and z in degrees per second
+    pub fn dps(&self) -> [f32; 3] {
+        self.dps
+    }
+
+    /// X, y and z in radians per second
+    pub fn rad_per_s(&self) -> [f32; 3] {
+        scale(self.dps, PI / 180.0)
+    }
+
+    /// The axes in `unit`, for code working with [`Sensor3DDataScaled`]
+    pub fn to_scaled(&self, unit: GyroUnit) -> Sensor3DDataScaled {
+        let [x, y, z] = match unit {
+            GyroUnit::DegreesPerSecond => self.dps(),
+            GyroUnit::RadiansPerSecond => self.rad_per_s(),
+        };
+        Sensor3DDataScaled { x, y, z }
+    }
+}
+
+impl Sensor3DData {
+    /// Convert raw accelerometer data to an [`Acceleration`]
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    pub fn to_acceleration(&self, range: AccelerometerRange) -> Acceleration {
+        self.to_acceleration_with_gravity(range, GRAVITY)
+    }
+
+    /// Convert raw accelerometer data to an [`Acceleration`] at a local gravity
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The accelerometer range the data was measured with
+    /// * `gravity` - The gravity in m/s^2
+    pub fn to_acceleration_with_gravity(
+        &self,
+        range: AccelerometerRange,
+        gravity: f32,
+    ) -> Acceleration {
+        let g_per_lsb = 1.0 / range.sensitivity();
+        Acceleration::from_g_with_gravity(scale(self.axes(), g_per_lsb), gravity)
+    }
+
+    /// Convert raw gyroscope data to an [`AngularRate`]
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The gyroscope range the data was measured with
+    pub fn to_angular_rate(&self, range: GyroscopeRange) -> AngularRate {
+        let dps_per_lsb = 1.0 / range.sensitivity();
+        AngularRate::from_dps(scale(self.axes(), dps_per_lsb))
+    }
+
+    /// The raw axes as floats
+    fn axes(&self) -> [f32; 3] {
+        [self.x, self.y, self.z].map(f32::from)
+    }
+}
+
+impl<DI, D, E> Bmi323<DI, D>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    /// Read the accelerometer as an [`Acceleration`]
+    ///
+    /// Not affected by [`Self::set_output_units`]; m/s^2 use the gravity set
+    /// with [`Self::set_gravity`].
+    pub fn read_accel(&mut self) -> Result<Acceleration, Error<E>> {
+        let raw_data = self.read_accel_data()?;
+        Ok(raw_data.to_acceleration_with_gravity(self.accel_range, self.gravity))
+    }
+
+    /// Read the gyroscope as an [`AngularRate`]
+    ///
+    /// Not affected by [`Self::set_output_units`].
+    pub fn read_gyro(&mut self) -> Result<AngularRate, Error<E>> {
+        let raw_data = self.read_gyro_data()?;
+        Ok(raw_data.to_angular_rate(self.gyro_range))
+    }
+}
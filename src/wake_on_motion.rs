@@ -0,0 +1,199 @@
+use crate::{
+    interface::{ReadData, WriteData},
+    types::InterruptPin,
+    AccelConfig, AccelerometerPowerMode, AverageNum, Bmi323, Error, GyroConfig, GyroscopePowerMode,
+    OutputDataRate, Register,
+};
+use embedded_hal::delay::DelayNs;
+
+/// Extended register holding the any-motion threshold and reference update
+const ANYMO_1: u16 = 0x05;
+/// Extended register holding the any-motion hysteresis
+const ANYMO_2: u16 = 0x06;
+/// Extended register holding the any-motion duration and wait time
+const ANYMO_3: u16 = 0x07;
+
+/// Any-motion enable bits for the x, y and z axes in FEATURE_IO0
+const ANY_MOTION_EN: u16 = 0b111 << 3;
+/// Any-motion bit in INT_STATUS_INT1/INT_STATUS_INT2
+const ANY_MOTION_STATUS: u16 = 1 << 1;
+
+/// Settings for [`WakeOnMotion`]
+///
+/// Thresholds are converted to the device's 1/512 g steps and the duration
+/// to 20 ms steps; values beyond the register fields saturate.
+#[derive(Debug, Clone, Copy)]
+pub struct WakeOnMotionConfig {
+    /// Acceleration change between samples that counts as motion, in mg
+    pub threshold_mg: u16,
+    /// Hysteresis applied to the threshold, in mg
+    pub hysteresis_mg: u16,
+    /// How long the threshold must be exceeded, in ms
+    pub duration_ms: u16,
+    /// Interrupt pin signalling motion
+    pub pin: InterruptPin,
+    /// Accelerometer configuration while waiting for motion
+    pub low_power: AccelConfig,
+}
+
+impl Default for WakeOnMotionConfig {
+    fn default() -> Self {
+        WakeOnMotionConfig {
+            threshold_mg: 100,
+            hysteresis_mg: 20,
+            duration_ms: 100,
+            pin: InterruptPin::Int1,
+            low_power: AccelConfig::builder()
+                .odr(OutputDataRate::Odr50hz)
+                .avg_num(AverageNum::Avg2)
+                .mode(AccelerometerPowerMode::LowPower)
+                .build(),
+        }
+    }
+}
+
+impl WakeOnMotionConfig {
+    /// ANYMO_1: slope_thres (bits 0-11) and acc_ref_up (bit 12)
+    fn anymo_1(&self) -> u16 {
+        mg_to_lsb(self.threshold_mg).min(0x0FFF) | (1 << 12)
+    }
+
+    /// ANYMO_2: hysteresis (bits 0-9)
+    fn anymo_2(&self) -> u16 {
+        mg_to_lsb(self.hysteresis_mg).min(0x03FF)
+    }
+
+    /// ANYMO_3: duration (bits 0-12), no wait time
+    fn anymo_3(&self) -> u16 {
+        (self.duration_ms / 20).min(0x1FFF)
+    }
+}
+
+/// Convert milli-g to the 1/512 g steps of the any-motion thresholds
+fn mg_to_lsb(mg: u16) -> u16 {
+    (u32::from(mg) * 512 / 1000) as u16
+}
+
+/// Wake-on-motion workflow
+///
+/// [`Self::enter`] turns the gyroscope off, puts the accelerometer into a low
+/// power configuration and routes the any-motion interrupt to a pin, so the
+/// host can sleep until the pin fires. [`Self::exit`] disables any-motion and
+/// restores the sensor configurations.
+///
+/// Unless set with [`Self::restore_accel`]/[`Self::restore_gyro`], the
+/// configurations restored are the ones applied when `enter` was called.
+/// Sensors that had not been configured are turned off.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WakeOnMotion {
+    config: WakeOnMotionConfig,
+    restore_accel: Option<AccelConfig>,
+    restore_gyro: Option<GyroConfig>,
+    saved_accel: Option<AccelConfig>,
+    saved_gyro: Option<GyroConfig>,
+}
+
+impl WakeOnMotion {
+    /// Create the helper with the given settings
+    pub fn new(config: WakeOnMotionConfig) -> Self {
+        WakeOnMotion {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Accelerometer configuration to apply on [`Self::exit`]
+    pub fn restore_accel(mut self, config: AccelConfig) -> Self {
+        self.restore_accel = Some(config);
+        self
+    }
+
+    /// Gyroscope configuration to apply on [`Self::exit`]
+    pub fn restore_gyro(mut self, config: GyroConfig) -> Self {
+        self.restore_gyro = Some(config);
+        self
+    }
+
+    /// Enter wake-on-motion mode
+    ///
+    /// Starts the feature engine if needed. The interrupt pin is configured
+    /// as an active-high push-pull output.
+    pub fn enter<DI, D, E>(&mut self, imu: &mut Bmi323<DI, D>) -> Result<(), Error<E>>
+    where
+        DI: ReadData<Error = E> + WriteData<Error = E>,
+        D: DelayNs,
+    {
+        self.saved_accel = imu.accel_config;
+        self.saved_gyro = imu.gyro_config;
+
+        imu.set_power_mode(Register::GYR_CONF, GyroscopePowerMode::Disable as u16)?;
+        imu.set_accel_config(self.config.low_power)?;
+
+        if imu.read_word(Register::FEATURE_CTRL)? & 0x0001 == 0 {
+            imu.enable_feature_engine()?;
+        }
+        write_extended(imu, ANYMO_1, self.config.anymo_1())?;
+        write_extended(imu, ANYMO_2, self.config.anymo_2())?;
+        write_extended(imu, ANYMO_3, self.config.anymo_3())?;
+        imu.modify_word(Register::FEATURE_IO0, ANY_MOTION_EN, ANY_MOTION_EN)?;
+        imu.write_word(Register::FEATURE_IO_STATUS, 0x0001)?;
+
+        // any_motion_out in bits 2-3
+        let pin = self.config.pin as u16;
+        imu.modify_word(Register::INT_MAP1, 0b11 << 2, pin << 2)?;
+        imu.enable_interrupt_pin(self.config.pin)?;
+
+        Ok(())
+    }
+
+    /// Whether the any-motion interrupt fired
+    ///
+    /// Reading the interrupt status clears it.
+    pub fn motion_detected<DI, D, E>(&self, imu: &mut Bmi323<DI, D>) -> Result<bool, Error<E>>
+    where
+        DI: ReadData<Error = E> + WriteData<Error = E>,
+        D: DelayNs,
+    {
+        let reg = match self.config.pin {
+            InterruptPin::Int1 => Register::INT_STATUS_INT1,
+            InterruptPin::Int2 => Register::INT_STATUS_INT2,
+        };
+        Ok(imu.read_word(reg)? & ANY_MOTION_STATUS != 0)
+    }
+
+    /// Leave wake-on-motion mode and restore the sensor configurations
+    ///
+    /// The feature engine is left running.
+    pub fn exit<DI, D, E>(&mut self, imu: &mut Bmi323<DI, D>) -> Result<(), Error<E>>
+    where
+        DI: ReadData<Error = E> + WriteData<Error = E>,
+        D: DelayNs,
+    {
+        imu.modify_word(Register::FEATURE_IO0, ANY_MOTION_EN, 0)?;
+        imu.write_word(Register::FEATURE_IO_STATUS, 0x0001)?;
+        imu.modify_word(Register::INT_MAP1, 0b11 << 2, 0)?;
+
+        let accel = self.restore_accel.or(self.saved_accel).unwrap_or(
+            AccelConfig::builder()
+                .mode(AccelerometerPowerMode::Disable)
+                .build(),
+        );
+        imu.set_accel_config(accel)?;
+        match self.restore_gyro.or(self.saved_gyro) {
+            Some(gyro) => imu.set_gyro_config(gyro)?,
+            None => imu.set_power_mode(Register::GYR_CONF, GyroscopePowerMode::Disable as u16)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a feature engine extended register
+fn write_extended<DI, D, E>(imu: &mut Bmi323<DI, D>, reg: u16, value: u16) -> Result<(), Error<E>>
+where
+    DI: ReadData<Error = E> + WriteData<Error = E>,
+    D: DelayNs,
+{
+    imu.write_word(Register::FEATURE_DATA_ADDR, reg)?;
+    imu.write_word(Register::FEATURE_DATA_TX, value)
+}
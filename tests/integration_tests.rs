@@ -1,16 +1,73 @@
+use bmi323::trace::{Access, TraceEvent};
 use bmi323::{
-    AccelConfig, AccelerometerPowerMode, AccelerometerRange, AverageNum, Bandwidth, Bmi323,
-    GyroConfig, GyroscopePowerMode, GyroscopeRange, OutputDataRate,
+    AccelConfig, AccelUnit, AccelerometerPowerMode, AccelerometerRange, AutoRangeConfig,
+    AverageNum, Bandwidth, Bmi323, ConfigError, DriveStrength, Error, GyroConfig, GyroUnit,
+    GyroscopePowerMode, GyroscopeRange, I2cInterface, I2cWatchdog, OutputDataRate, ReadData,
+    SensorType, SlaveAddress, SpiInterface, SpiMode, Timebase, TraceRing, Traced, WakeOnMotion,
+    WakeOnMotionConfig, WriteData,
 };
+use core::time::Duration;
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
 use embedded_hal_mock::eh1::delay::NoopDelay as MockDelay;
 use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+
+/// Expected I2C transactions for reading `data` starting at `register`
+fn i2c_read(register: u8, data: Vec<u8>) -> Vec<I2cTransaction> {
+    i2c_read_at(0x68, register, data)
+}
+
+/// Expected I2C transactions for reading `data` starting at `register` of the
+/// device at `address`
+fn i2c_read_at(address: u8, register: u8, data: Vec<u8>) -> Vec<I2cTransaction> {
+    vec![
+        I2cTransaction::transaction_start(address),
+        I2cTransaction::write(address, vec![register]),
+        I2cTransaction::read(address, vec![0x00, 0x00]), // Dummy bytes
+        I2cTransaction::read(address, data),
+        I2cTransaction::transaction_end(address),
+    ]
+}
+
+/// Expected I2C transactions for writing `data` starting at `register`
+fn i2c_write(register: u8, data: Vec<u8>) -> Vec<I2cTransaction> {
+    vec![
+        I2cTransaction::transaction_start(0x68),
+        I2cTransaction::write(0x68, vec![register]),
+        I2cTransaction::write(0x68, data),
+        I2cTransaction::transaction_end(0x68),
+    ]
+}
+
+/// Expected SPI transactions for reading `data` starting at `register`
+fn spi_read(register: u8, data: Vec<u8>) -> Vec<SpiTransaction<u8>> {
+    vec![
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write(register | 0x80),
+        SpiTransaction::read(0xFF), // Dummy byte
+        SpiTransaction::read_vec(data),
+        SpiTransaction::transaction_end(),
+    ]
+}
+
+/// Expected SPI transactions for writing `data` starting at `register`
+fn spi_write(register: u8, data: Vec<u8>) -> Vec<SpiTransaction<u8>> {
+    vec![
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write(register),
+        SpiTransaction::write_vec(data),
+        SpiTransaction::transaction_end(),
+    ]
+}
 
 #[test]
 fn test_bmi323_init() {
     let expectations = [
-        I2cTransaction::write(0x68, vec![0x7E, 0xAF, 0xDE]),
-        I2cTransaction::write_read(0x68, vec![0x00], vec![0x43]),
-    ];
+        i2c_write(0x7E, vec![0xAF, 0xDE]),
+        i2c_read(0x01, vec![0x00, 0x00]), // Error register
+        i2c_read(0x00, vec![0x43]),       // Chip ID
+    ]
+    .concat();
 
     let mut i2c = I2cMock::new(&expectations);
     let delay = MockDelay::new();
@@ -22,12 +79,197 @@ fn test_bmi323_init() {
 }
 
 #[test]
-fn test_bmi323_set_sensor_config() {
+fn test_bmi323_init_resets_cached_ranges() {
+    let expectations = [
+        i2c_write(0x20, vec![0x08, 0x40]), // ±2g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_write(0x21, vec![0x08, 0x40]), // ±125°/s
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_write(0x7E, vec![0xAF, 0xDE]),
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x00, vec![0x43]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    bmi323.set_config_wait(None);
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G2)
+        .bw(Bandwidth::OdrHalf)
+        .build();
+    let gyro_config = GyroConfig::builder()
+        .range(GyroscopeRange::DPS125)
+        .bw(Bandwidth::OdrHalf)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+    bmi323.set_gyro_config(gyro_config).unwrap();
+
+    // The soft reset restores the default ranges with both sensors off
+    bmi323.init().unwrap();
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G8);
+    assert_eq!(bmi323.gyro_range(), GyroscopeRange::DPS2000);
+    // Nothing left to restore
+    bmi323.resume().unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_spi_init() {
+    let expectations = [
+        spi_read(0x00, vec![0x00]), // Dummy read switching to SPI after power-on
+        spi_write(0x7E, vec![0xAF, 0xDE]),
+        spi_read(0x00, vec![0x00]), // Dummy read switching to SPI after the reset
+        spi_read(0x01, vec![0x00, 0x00]), // Error register
+        spi_read(0x00, vec![0x43]), // Chip ID
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi.clone(), delay);
+
+    bmi323.init().unwrap();
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_spi_init_wrong_chip_id() {
+    let expectations = [
+        spi_read(0x00, vec![0x00]),
+        spi_write(0x7E, vec![0xAF, 0xDE]),
+        spi_read(0x00, vec![0x00]),
+        spi_read(0x01, vec![0x00, 0x00]),
+        spi_read(0x00, vec![0x24]),
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi.clone(), delay);
+
+    assert!(matches!(bmi323.init(), Err(Error::InvalidDevice)));
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_spi_3wire_init() {
+    let expectations = [
+        spi_read(0x00, vec![0x00]),        // Dummy read switching to SPI
+        spi_write(0x50, vec![0x01, 0x00]), // IO_SPI_IF: 3-wire
+        spi_write(0x7E, vec![0xAF, 0xDE]),
+        spi_read(0x00, vec![0x00]), // The reset restores 4-wire mode
+        spi_write(0x50, vec![0x01, 0x00]),
+        spi_read(0x01, vec![0x00, 0x00]),
+        spi_read(0x00, vec![0x43]),
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi_mode(spi.clone(), SpiMode::ThreeWire, delay);
+
+    bmi323.init().unwrap();
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_traced_spi_3wire_init() {
+    let expectations = [
+        spi_read(0x00, vec![0x00]),
+        spi_write(0x50, vec![0x01, 0x00]),
+        spi_write(0x7E, vec![0xAF, 0xDE]),
+        spi_read(0x00, vec![0x00]),
+        spi_write(0x50, vec![0x01, 0x00]),
+        spi_read(0x01, vec![0x00, 0x00]),
+        spi_read(0x00, vec![0x43]),
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let mut log = Vec::new();
+    let iface = Traced::new(
+        SpiInterface::new(spi.clone(), SpiMode::ThreeWire),
+        |event: &TraceEvent<'_>| log.push((event.access, event.register)),
+    );
+    let mut bmi323 = Bmi323::new(iface, MockDelay::new());
+
+    bmi323.init().unwrap();
+    drop(bmi323);
+
+    // The interface selection is recorded like any other access
+    assert_eq!(
+        log,
+        vec![
+            (Access::Read, 0x00),
+            (Access::Write, 0x50),
+            (Access::Write, 0x7E),
+            (Access::Read, 0x00),
+            (Access::Write, 0x50),
+            (Access::Read, 0x01),
+            (Access::Read, 0x00),
+        ]
+    );
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_spi_3wire_half_duplex_read() {
+    // Address byte written, then dummy and data bytes read, all in one transaction
     let expectations = [
-        I2cTransaction::write(0x68, vec![0x20, 0xB8, 0x46]), // Accelerometer config
-        I2cTransaction::write(0x68, vec![0x21, 0x48, 0x46]), // Gyroscope config
+        SpiTransaction::transaction_start(),
+        SpiTransaction::write(0x8A),
+        SpiTransaction::read(0x00),
+        SpiTransaction::read_vec(vec![0x04, 0x03, 0x02, 0x01]),
+        SpiTransaction::transaction_end(),
     ];
 
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi_mode(spi.clone(), SpiMode::ThreeWire, delay);
+
+    assert_eq!(bmi323.read_sensor_time().unwrap(), 0x0102_0304);
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_spi_read_sensor_data() {
+    let mut data = vec![0u8; 14];
+    data[0..6].copy_from_slice(&[0x01, 0x00, 0xFF, 0xFF, 0x00, 0x80]);
+    let expectations = spi_read(0x06, data);
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi.clone(), delay);
+
+    let sensor_data = bmi323.read_gyro_data().unwrap();
+    assert_eq!(sensor_data.x, 1);
+    assert_eq!(sensor_data.y, -1);
+    assert_eq!(sensor_data.z, i16::MIN);
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_set_sensor_config() {
+    let expectations = [
+        i2c_write(0x20, vec![0xB8, 0x40]), // Accelerometer config
+        i2c_read(0x01, vec![0x00, 0x00]),  // No acc_conf_err
+        i2c_read(0x02, vec![0x80]),        // drdy_acc
+        i2c_write(0x21, vec![0x48, 0x40]), // Gyroscope config
+        i2c_read(0x01, vec![0x00, 0x00]),  // No gyr_conf_err
+        i2c_read(0x02, vec![0x40]),        // drdy_gyr
+    ]
+    .concat();
+
     let mut i2c = I2cMock::new(&expectations);
     let delay = MockDelay::new();
     let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
@@ -36,7 +278,7 @@ fn test_bmi323_set_sensor_config() {
         .odr(OutputDataRate::Odr100hz)
         .range(AccelerometerRange::G16)
         .bw(Bandwidth::OdrQuarter) // ODR/4
-        .avg_num(AverageNum::Avg64)
+        .avg_num(AverageNum::Avg1)
         .mode(AccelerometerPowerMode::Normal)
         .build();
 
@@ -44,7 +286,7 @@ fn test_bmi323_set_sensor_config() {
         .odr(OutputDataRate::Odr100hz)
         .range(GyroscopeRange::DPS2000)
         .bw(Bandwidth::OdrHalf) // ODR/2
-        .avg_num(AverageNum::Avg64)
+        .avg_num(AverageNum::Avg1)
         .mode(GyroscopePowerMode::Normal)
         .build();
 
@@ -56,11 +298,7 @@ fn test_bmi323_set_sensor_config() {
 
 #[test]
 fn test_bmi323_read_sensor_data() {
-    let expectations = [I2cTransaction::write_read(
-        0x68,
-        vec![0x03],
-        vec![0, 0, 0, 0, 0, 0],
-    )];
+    let expectations = i2c_read(0x03, vec![0; 20]);
 
     let mut i2c = I2cMock::new(&expectations);
     let delay = MockDelay::new();
@@ -73,3 +311,1137 @@ fn test_bmi323_read_sensor_data() {
 
     i2c.done();
 }
+
+#[test]
+fn test_bmi323_read_sensor_time() {
+    let expectations = i2c_read(0x0A, vec![0x78, 0x56, 0x34, 0x12]);
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    assert_eq!(bmi323.read_sensor_time().unwrap(), 0x1234_5678);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_read_all() {
+    let expectations = i2c_read(
+        0x03,
+        vec![
+            0x00, 0x10, 0x00, 0xF0, 0x00, 0x00, // Accelerometer
+            0x01, 0x00, 0x02, 0x00, 0x03, 0x00, // Gyroscope
+            0x00, 0x02, // Temperature
+            0x04, 0x03, 0x02, 0x01, // Sensor time
+            0x09, 0x00, // Saturation flags
+        ],
+    );
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let data = bmi323.read_all().unwrap();
+    assert_eq!(data.accel.x, 0x1000);
+    assert_eq!(data.accel.y, -0x1000);
+    assert_eq!(data.accel.z, 0);
+    assert_eq!((data.gyro.x, data.gyro.y, data.gyro.z), (1, 2, 3));
+    assert_eq!(data.temperature, 512);
+    assert_eq!(data.sensor_time, 0x0102_0304);
+    assert!(data.saturation.acc_x && data.saturation.gyr_x);
+    assert!(!data.saturation.acc_y && !data.saturation.gyr_z);
+
+    let scaled = data.to_scaled(AccelerometerRange::G8, GyroscopeRange::DPS2000);
+    assert_eq!(scaled.temperature, 24.0);
+    assert_eq!(
+        scaled.accel,
+        data.accel
+            .to_accel_units(AccelerometerRange::G8, AccelUnit::MetersPerSecondSquared)
+    );
+    assert_eq!(
+        scaled.gyro,
+        data.gyro
+            .to_gyro_units(GyroscopeRange::DPS2000, GyroUnit::DegreesPerSecond)
+    );
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_local_gravity() {
+    let mut burst = vec![0u8; 20];
+    burst[0..2].copy_from_slice(&4096i16.to_le_bytes()); // 1 g at ±8g
+
+    let expectations = [
+        i2c_read(0x03, burst.clone()),
+        i2c_read(0x03, burst.clone()),
+        i2c_read(0x03, burst),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    assert_eq!(bmi323.gravity(), bmi323::GRAVITY);
+    bmi323.set_gravity(9.81);
+    assert_eq!(bmi323.read_accel_data_scaled().unwrap().x, 9.81);
+    assert_eq!(bmi323.read_all_scaled().unwrap().accel.x, 9.81);
+
+    // Readings in g do not depend on the gravity
+    bmi323.set_output_units(AccelUnit::G, GyroUnit::DegreesPerSecond);
+    assert_eq!(bmi323.read_accel_data_scaled().unwrap().x, 1.0);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_auto_range_steps_up_on_saturation() {
+    let mut saturated = vec![0u8; 20];
+    saturated[0..2].copy_from_slice(&i16::MAX.to_le_bytes());
+    saturated[18] = 0x01; // acc_x saturated
+    let mut half_scale = vec![0u8; 20];
+    half_scale[0..2].copy_from_slice(&0x4000i16.to_le_bytes());
+
+    let expectations = [
+        i2c_write(0x20, vec![0x28, 0x40]), // ±8g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x03, saturated.clone()),
+        i2c_read(0x03, saturated.clone()),
+        // The switch is written before the next read, after clearing drdy
+        i2c_read(0x02, vec![0x80]),
+        i2c_write(0x20, vec![0x38, 0x40]), // ±16g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x00]),
+        i2c_read(0x02, vec![0x80]), // First sample at ±16g
+        i2c_read(0x03, half_scale),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G8)
+        .bw(Bandwidth::OdrHalf)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+    bmi323.enable_auto_range(AutoRangeConfig {
+        saturation_count: 2,
+        ..AutoRangeConfig::default()
+    });
+
+    let first = bmi323.read_accel_data_scaled().unwrap();
+    let second = bmi323.read_accel_data_scaled().unwrap();
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G8);
+    // The sample that triggered the switch is still returned
+    assert_eq!(first, second);
+    assert!((first.x - 8.0 * 9.80665).abs() < 0.01);
+
+    // Samples are scaled with the range they were measured with
+    let third = bmi323.read_accel_data_scaled().unwrap();
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G16);
+    assert!((third.x - 8.0 * 9.80665).abs() < 0.01);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_auto_range_waits_for_sample_at_new_range() {
+    let mut saturated = vec![0u8; 20];
+    saturated[0..2].copy_from_slice(&i16::MAX.to_le_bytes());
+    saturated[18] = 0x01; // acc_x saturated
+    let mut half_scale = vec![0u8; 20];
+    half_scale[0..2].copy_from_slice(&0x4000i16.to_le_bytes());
+
+    let expectations = [
+        i2c_write(0x20, vec![0x18, 0x40]), // ±4g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x03, saturated.clone()),
+        // Non-blocking: the switch clears the stale drdy and would block
+        i2c_read(0x02, vec![0x80]),
+        i2c_write(0x20, vec![0x28, 0x40]), // ±8g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x03, saturated),
+        // Blocking: the read waits for drdy despite the disabled config wait
+        i2c_read(0x02, vec![0x80]),
+        i2c_write(0x20, vec![0x38, 0x40]), // ±16g
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x03, half_scale),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    bmi323.set_config_wait(None);
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G4)
+        .bw(Bandwidth::OdrHalf)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+    bmi323.enable_auto_range(AutoRangeConfig {
+        saturation_count: 1,
+        ..AutoRangeConfig::default()
+    });
+
+    assert_eq!(bmi323.try_read_accel().unwrap().x, i16::MAX);
+    assert!(matches!(
+        bmi323.try_read_accel(),
+        Err(nb::Error::WouldBlock)
+    ));
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G8);
+    assert!(matches!(
+        bmi323.try_read_accel(),
+        Err(nb::Error::WouldBlock)
+    ));
+    assert_eq!(bmi323.try_read_accel().unwrap().x, i16::MAX);
+
+    // Half of ±16g, not half of ±8g from the sample before the switch
+    let scaled = bmi323.read_accel_data_scaled().unwrap();
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G16);
+    assert!((scaled.x - 8.0 * 9.80665).abs() < 0.01);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_auto_range_reports_failed_switch() {
+    let mut saturated = vec![0u8; 14];
+    saturated[4..6].copy_from_slice(&i16::MIN.to_le_bytes());
+    saturated[12] = 0x20; // gyr_z saturated
+
+    let expectations = [
+        i2c_write(0x21, vec![0x38, 0x40]), // ±1000°/s
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x06, saturated),
+        // The rejected switch is reported by the next read and then dropped
+        i2c_read(0x02, vec![0x00]),
+        i2c_write(0x21, vec![0x48, 0x40]),
+        i2c_read(0x01, vec![0x40, 0x00]), // gyr_conf error
+        i2c_read(0x06, vec![0u8; 14]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    bmi323.set_config_wait(None);
+    let gyro_config = GyroConfig::builder().range(GyroscopeRange::DPS1000).build();
+    bmi323.set_gyro_config(gyro_config).unwrap();
+    bmi323.enable_auto_range(AutoRangeConfig {
+        saturation_count: 1,
+        ..AutoRangeConfig::default()
+    });
+
+    assert_eq!(bmi323.read_gyro_data().unwrap().z, i16::MIN);
+    assert!(matches!(
+        bmi323.read_gyro_data(),
+        Err(Error::InvalidConfig(ConfigError::Rejected(
+            SensorType::Gyroscope
+        )))
+    ));
+    assert_eq!(bmi323.read_gyro_data().unwrap().z, 0);
+    assert_eq!(bmi323.gyro_range(), GyroscopeRange::DPS1000);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_get_sensor_config() {
+    let expectations = [
+        i2c_read(0x20, vec![0x18, 0x40]),
+        i2c_read(0x21, vec![0x28, 0x70]),
+        i2c_read(0x20, vec![0x58, 0x40]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let accel_config = bmi323.get_accel_config().unwrap();
+    assert_eq!(accel_config.odr, OutputDataRate::Odr100hz);
+    assert_eq!(accel_config.range, AccelerometerRange::G4);
+    assert_eq!(accel_config.mode, AccelerometerPowerMode::Normal);
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G4);
+
+    let gyro_config = bmi323.get_gyro_config().unwrap();
+    assert_eq!(gyro_config.range, GyroscopeRange::DPS500);
+    assert_eq!(gyro_config.mode, GyroscopePowerMode::HighPerf);
+    assert_eq!(bmi323.gyro_range(), GyroscopeRange::DPS500);
+
+    // Reserved range code
+    assert!(bmi323.get_accel_config().is_err());
+    assert_eq!(bmi323.accel_range(), AccelerometerRange::G4);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_invalid_config_is_not_written() {
+    let mut i2c = I2cMock::new(&[]);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let gyro_config = GyroConfig::builder()
+        .odr(OutputDataRate::Odr6400hz)
+        .mode(GyroscopePowerMode::Normal)
+        .build();
+    assert!(matches!(
+        bmi323.set_gyro_config(gyro_config),
+        Err(Error::InvalidConfig(ConfigError::OdrNotSupported))
+    ));
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_rejected_config_is_reported() {
+    let expectations = [
+        i2c_write(0x20, vec![0xA8, 0x40]),
+        i2c_read(0x01, vec![0x20, 0x00]), // acc_conf_err
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    assert!(matches!(
+        bmi323.set_accel_config(AccelConfig::builder().build()),
+        Err(Error::InvalidConfig(ConfigError::Rejected(
+            SensorType::Accelerometer
+        )))
+    ));
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_init_reports_fatal_error() {
+    let expectations = [
+        i2c_write(0x7E, vec![0xAF, 0xDE]),
+        i2c_read(0x01, vec![0x01, 0x00]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    match bmi323.init() {
+        Err(Error::Fatal(errors)) => assert!(errors.fatal),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_check_errors() {
+    let expectations = [
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x01, vec![0x50, 0x00]), // feat_eng_wd and gyr_conf_err
+        i2c_read(0x01, vec![0x40, 0x00]),
+        i2c_read(0x01, vec![0x00, 0x08]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    assert!(bmi323.check_errors().is_ok());
+    match bmi323.check_errors() {
+        Err(Error::FeatureEngine(errors)) => {
+            assert!(errors.feature_engine_watchdog && errors.gyr_conf)
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(matches!(
+        bmi323.check_errors(),
+        Err(Error::InvalidConfig(ConfigError::Rejected(
+            SensorType::Gyroscope
+        )))
+    ));
+    match bmi323.check_errors() {
+        Err(Error::I3c(errors)) => assert!(errors.i3c_error3 && !errors.i3c_error0),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_word_access() {
+    let expectations = [
+        i2c_read(0x38, vec![0x34, 0x12]),
+        i2c_write(0x38, vec![0xCD, 0xAB]),
+        i2c_read(0x38, vec![0x34, 0x12]),
+        i2c_write(0x38, vec![0x04, 0x12]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    assert_eq!(bmi323.read_word(0x38).unwrap(), 0x1234);
+    bmi323.write_word(0x38, 0xABCD).unwrap();
+    bmi323.modify_word(0x38, 0x00FF, 0x0004).unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_spi_word_access() {
+    let expectations = [
+        spi_read(0x01, vec![0x02, 0x80]),
+        spi_write(0x21, vec![0x48, 0x40]),
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi.clone(), delay);
+
+    assert_eq!(bmi323.read_word(0x01).unwrap(), 0x8002);
+    bmi323.write_word(0x21, 0x4048).unwrap();
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_long_burst_read() {
+    let fifo: Vec<u8> = (0..=255).collect();
+    let expectations = i2c_read(0x16, fifo.clone());
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let mut data = [0u8; 256];
+    bmi323.read_burst(0x16, &mut data).unwrap();
+    assert_eq!(data.to_vec(), fifo);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_spi_long_burst_read() {
+    let fifo: Vec<u8> = (0..=255).rev().collect();
+    let expectations = spi_read(0x16, fifo.clone());
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi.clone(), delay);
+
+    let mut data = [0u8; 256];
+    bmi323.read_burst(0x16, &mut data).unwrap();
+    assert_eq!(data.to_vec(), fifo);
+
+    spi.done();
+}
+
+#[test]
+fn test_scan_i2c() {
+    let expectations = [
+        I2cTransaction::write_read(0x68, vec![0x00], vec![0x00, 0x00, 0x43]),
+        I2cTransaction::write_read(0x69, vec![0x00], vec![0x00, 0x00, 0x00])
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        I2cTransaction::write_read(0x68, vec![0x00], vec![0x00, 0x00, 0x24]),
+        I2cTransaction::write_read(0x69, vec![0x00], vec![0x00, 0x00, 0x43]),
+        I2cTransaction::write_read(0x68, vec![0x00], vec![0x00, 0x00, 0x00])
+            .with_error(ErrorKind::ArbitrationLoss),
+    ];
+
+    let mut i2c = I2cMock::new(&expectations);
+
+    assert_eq!(
+        bmi323::scan_i2c(&mut i2c).unwrap(),
+        [Some(SlaveAddress::SdoLow), None]
+    );
+    assert_eq!(
+        bmi323::scan_i2c(&mut i2c).unwrap(),
+        [None, Some(SlaveAddress::SdoHigh)]
+    );
+    assert!(matches!(
+        bmi323::probe_i2c(&mut i2c, SlaveAddress::SdoLow),
+        Err(Error::Comm(ErrorKind::ArbitrationLoss))
+    ));
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_slave_address() {
+    let expectations = i2c_read_at(0x69, 0x0A, vec![0x00, 0x00, 0x00, 0x00]);
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), SlaveAddress::SdoHigh, delay);
+
+    assert_eq!(bmi323.read_sensor_time().unwrap(), 0);
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_destroy_returns_bus() {
+    let expectations = i2c_read(0x0A, vec![0x00, 0x00, 0x00, 0x00]);
+
+    let i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c, 0x68, delay);
+    bmi323.read_sensor_time().unwrap();
+
+    let (mut i2c, _delay) = bmi323.destroy();
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_spi_destroy_returns_bus() {
+    let expectations = [
+        spi_read(0x38, vec![0x01, 0x00]),
+        spi_write(0x38, vec![0x05, 0x00]),
+    ]
+    .concat();
+
+    let spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_spi(spi, delay);
+    bmi323
+        .iface_mut()
+        .modify_word(0x38, 0x0004, 0x0004)
+        .unwrap();
+
+    let (mut spi, _delay) = bmi323.destroy();
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_traced_i2c() {
+    let expectations = [
+        i2c_read(0x38, vec![0x34, 0x12]),
+        i2c_write(0x38, vec![0xCD, 0xAB]),
+        i2c_write(0x38, vec![0x01, 0x00]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let iface = Traced::new(
+        I2cInterface::new(i2c.clone(), SlaveAddress::SdoLow),
+        TraceRing::<2>::new(),
+    );
+    let mut bmi323 = Bmi323::new(iface, delay);
+
+    assert_eq!(bmi323.read_word(0x38).unwrap(), 0x1234);
+    bmi323.write_word(0x38, 0xABCD).unwrap();
+    bmi323.write_word(0x38, 0x0001).unwrap();
+
+    // The ring only keeps the last two accesses
+    let (iface, _delay) = bmi323.into_parts();
+    let records: Vec<_> = iface.sink().iter().copied().collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].access, Access::Write);
+    assert_eq!(records[0].register, 0x38);
+    assert_eq!(records[0].data(), &[0xCD, 0xAB]);
+    assert!(records[0].ok);
+    assert_eq!(records[1].data(), &[0x01, 0x00]);
+
+    i2c.done();
+}
+
+/// Transport whose every access fails
+struct Unplugged;
+
+impl ReadData for Unplugged {
+    type Error = ErrorKind;
+    fn read_data(&mut self, _register: u8, _data: &mut [u8]) -> Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+}
+
+impl WriteData for Unplugged {
+    type Error = ErrorKind;
+    fn write_data(&mut self, _register: u8, _data: &[u8]) -> Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+}
+
+#[test]
+fn test_traced_records_failed_access() {
+    let iface = Traced::new(Unplugged, TraceRing::<2>::new());
+    let mut bmi323 = Bmi323::new(iface, MockDelay::new());
+
+    assert!(matches!(
+        bmi323.write_word(0x38, 0x0001),
+        Err(Error::Comm(ErrorKind::Other))
+    ));
+
+    let (iface, _delay) = bmi323.into_parts();
+    let record = iface.sink().iter().next().unwrap();
+    assert_eq!(record.access, Access::Write);
+    assert_eq!(record.data(), &[0x01, 0x00]);
+    assert!(!record.ok);
+}
+
+#[test]
+fn test_bmi323_traced_spi() {
+    let expectations = [
+        spi_read(0x01, vec![0x02, 0x80]),
+        spi_write(0x21, vec![0x48, 0x40]),
+    ]
+    .concat();
+
+    let mut spi = SpiMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut log = Vec::new();
+    let iface = Traced::new(
+        SpiInterface::new(spi.clone(), SpiMode::FourWire),
+        |event: &TraceEvent<'_>| log.push((event.access, event.register, event.data.to_vec())),
+    );
+    let mut bmi323 = Bmi323::new(iface, delay);
+
+    assert_eq!(bmi323.read_word(0x01).unwrap(), 0x8002);
+    bmi323.write_word(0x21, 0x4048).unwrap();
+    drop(bmi323);
+
+    assert_eq!(
+        log,
+        vec![
+            (Access::Read, 0x01, vec![0x02, 0x80]),
+            (Access::Write, 0x21, vec![0x48, 0x40]),
+        ]
+    );
+
+    spi.done();
+}
+
+#[test]
+fn test_bmi323_io_config() {
+    let expectations = [
+        i2c_read(0x52, vec![0x00, 0x00]),
+        i2c_write(0x52, vec![0x03, 0x00]),
+        i2c_read(0x52, vec![0x03, 0x00]),
+        i2c_read(0x51, vec![0x1A, 0x00]),
+        i2c_write(0x51, vec![0x1F, 0x00]),
+        i2c_read(0x51, vec![0x1F, 0x00]),
+        i2c_read(0x51, vec![0x1F, 0x00]),
+        i2c_write(0x51, vec![0x12, 0x00]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    bmi323.set_i2c_watchdog(I2cWatchdog::Timeout40ms).unwrap();
+    assert_eq!(bmi323.get_i2c_watchdog().unwrap(), I2cWatchdog::Timeout40ms);
+    let boosted = DriveStrength::MAX.with_i2c_boost(true);
+    bmi323.set_pad_drive_strength(boosted).unwrap();
+    assert_eq!(bmi323.get_pad_drive_strength().unwrap(), boosted);
+    bmi323
+        .set_pad_drive_strength(DriveStrength::try_from(2).unwrap())
+        .unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_typestate() {
+    let expectations = [
+        i2c_write(0x7E, vec![0xAF, 0xDE]),
+        i2c_read(0x01, vec![0x00, 0x00]),  // Error register
+        i2c_read(0x00, vec![0x43]),        // Chip ID
+        i2c_write(0x20, vec![0xB8, 0x40]), // Accelerometer on
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x03, vec![0; 20]),
+        i2c_write(0x20, vec![0xA8, 0x00]), // Accelerometer off
+        i2c_read(0x01, vec![0x00, 0x00]),  // No data ready wait
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let imu = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay).into_typed();
+
+    let imu = imu.init().map_err(|(_, e)| e).unwrap();
+
+    // A disabled configuration does not enable the sensor
+    let disabled = AccelConfig::builder()
+        .mode(AccelerometerPowerMode::Disable)
+        .build();
+    let (imu, error) = imu.enable_accel(disabled).err().unwrap();
+    assert!(matches!(
+        error,
+        Error::InvalidConfig(ConfigError::InvalidMode)
+    ));
+
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G16)
+        .build();
+    let mut imu = imu.enable_accel(accel_config).map_err(|(_, e)| e).unwrap();
+    assert_eq!(imu.read_accel_data().unwrap().x, 0);
+
+    let imu = imu.disable_accel().map_err(|(_, e)| e).unwrap();
+    let _bmi323 = imu.into_inner();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_suspend_resume() {
+    let expectations = [
+        i2c_write(0x20, vec![0xB8, 0x40]), // Accelerometer config
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        // suspend(true)
+        i2c_read(0x20, vec![0xB8, 0x40]),
+        i2c_write(0x20, vec![0xB8, 0x00]),
+        i2c_read(0x21, vec![0x48, 0x40]),
+        i2c_write(0x21, vec![0x48, 0x00]),
+        i2c_read(0x40, vec![0x01, 0x00]), // Feature engine running
+        i2c_write(0x40, vec![0x00, 0x00]),
+        // resume()
+        i2c_write(0x12, vec![0x2C, 0x01]),
+        i2c_write(0x14, vec![0x01, 0x00]),
+        i2c_write(0x40, vec![0x01, 0x00]),
+        i2c_read(0x11, vec![0x01, 0x00]), // Feature engine started
+        i2c_write(0x20, vec![0xB8, 0x40]),
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        // suspend_gyro()
+        i2c_read(0x21, vec![0x48, 0x40]),
+        i2c_write(0x21, vec![0x48, 0x10]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G16)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+
+    bmi323.suspend(true).unwrap();
+    // Only the previously configured accelerometer is restored
+    bmi323.resume().unwrap();
+    bmi323.suspend_gyro().unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_resume_after_config_readback() {
+    let expectations = [
+        i2c_write(0x20, vec![0xB8, 0x40]), // Accelerometer config
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        // suspend(false)
+        i2c_read(0x20, vec![0xB8, 0x40]),
+        i2c_write(0x20, vec![0xB8, 0x00]),
+        i2c_read(0x21, vec![0x48, 0x40]),
+        i2c_write(0x21, vec![0x48, 0x00]),
+        // get_accel_config() while suspended
+        i2c_read(0x20, vec![0xB8, 0x00]),
+        // resume() restores the written configuration, not the readback
+        i2c_write(0x20, vec![0xB8, 0x40]),
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G16)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+    bmi323.suspend(false).unwrap();
+
+    let readback = bmi323.get_accel_config().unwrap();
+    assert_eq!(readback.mode, AccelerometerPowerMode::Disable);
+    assert_eq!(readback.range, AccelerometerRange::G16);
+
+    bmi323.resume().unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_wake_on_motion() {
+    let expectations = [
+        // enter()
+        i2c_read(0x21, vec![0x00, 0x00]),
+        i2c_write(0x21, vec![0x00, 0x00]), // Gyroscope off
+        i2c_write(0x20, vec![0xA7, 0x31]), // Low power 50 Hz, Avg2
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x40, vec![0x00, 0x00]), // Feature engine off
+        i2c_write(0x12, vec![0x2C, 0x01]),
+        i2c_write(0x14, vec![0x01, 0x00]),
+        i2c_write(0x40, vec![0x01, 0x00]),
+        i2c_read(0x11, vec![0x01, 0x00]),
+        i2c_write(0x41, vec![0x05, 0x00]),
+        i2c_write(0x42, vec![0x33, 0x10]), // 100 mg, acc_ref_up
+        i2c_write(0x41, vec![0x06, 0x00]),
+        i2c_write(0x42, vec![0x0A, 0x00]), // 20 mg
+        i2c_write(0x41, vec![0x07, 0x00]),
+        i2c_write(0x42, vec![0x05, 0x00]), // 100 ms
+        i2c_read(0x10, vec![0x00, 0x00]),
+        i2c_write(0x10, vec![0x38, 0x00]), // Any-motion x/y/z
+        i2c_write(0x14, vec![0x01, 0x00]),
+        i2c_read(0x3A, vec![0x00, 0x00]),
+        i2c_write(0x3A, vec![0x04, 0x00]), // Any-motion to INT1
+        i2c_read(0x38, vec![0x00, 0x00]),
+        i2c_write(0x38, vec![0x05, 0x00]), // INT1 active high
+        // motion_detected()
+        i2c_read(0x0D, vec![0x02, 0x00]),
+        // exit()
+        i2c_read(0x10, vec![0x38, 0x00]),
+        i2c_write(0x10, vec![0x00, 0x00]),
+        i2c_write(0x14, vec![0x01, 0x00]),
+        i2c_read(0x3A, vec![0x04, 0x00]),
+        i2c_write(0x3A, vec![0x00, 0x00]),
+        i2c_write(0x20, vec![0xB8, 0x40]), // Restored accelerometer
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]),
+        i2c_read(0x21, vec![0x00, 0x00]), // Gyroscope was never configured
+        i2c_write(0x21, vec![0x00, 0x00]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G16)
+        .build();
+    let mut wake = WakeOnMotion::new(WakeOnMotionConfig::default()).restore_accel(accel_config);
+
+    wake.enter(&mut bmi323).unwrap();
+    assert!(wake.motion_detected(&mut bmi323).unwrap());
+    wake.exit(&mut bmi323).unwrap();
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_non_blocking_reads() {
+    let expectations = [
+        i2c_write(0x20, vec![0xB8, 0x40]), // Accelerometer config
+        i2c_read(0x01, vec![0x00, 0x00]),  // No data ready wait
+        i2c_read(0x02, vec![0x00]),        // Not ready yet
+        i2c_read(0x02, vec![0x80]),        // drdy_acc
+        i2c_read(0x03, vec![0; 20]),
+        i2c_read(0x02, vec![0x80]), // drdy_acc only
+        i2c_read(0x02, vec![0xC0]), // drdy_acc and drdy_gyr
+        i2c_read(0x03, vec![0; 20]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    bmi323.set_config_wait(None);
+    let accel_config = AccelConfig::builder()
+        .range(AccelerometerRange::G16)
+        .build();
+    bmi323.set_accel_config(accel_config).unwrap();
+
+    assert!(matches!(
+        bmi323.try_read_accel(),
+        Err(nb::Error::WouldBlock)
+    ));
+    assert_eq!(bmi323.try_read_accel().unwrap().x, 0);
+    assert!(matches!(bmi323.try_read_all(), Err(nb::Error::WouldBlock)));
+    assert_eq!(bmi323.try_read_all().unwrap().gyro.z, 0);
+
+    i2c.done();
+}
+
+#[test]
+#[cfg(feature = "units")]
+fn test_bmi323_typed_unit_reads() {
+    let mut accel = vec![0u8; 20];
+    accel[0..2].copy_from_slice(&4096i16.to_le_bytes()); // 1 g at ±8g
+    let mut gyro = vec![0u8; 14];
+    gyro[4..6].copy_from_slice(&(-1638i16).to_le_bytes()); // about -100 dps at ±2000 dps
+
+    let expectations = [i2c_read(0x03, accel), i2c_read(0x06, gyro)].concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, MockDelay::new());
+    // Typed reads ignore the output units of the scaled reads
+    bmi323.set_output_units(AccelUnit::G, GyroUnit::RadiansPerSecond);
+
+    let acceleration = bmi323.read_accel().unwrap();
+    assert_eq!(acceleration.g(), [1.0, 0.0, 0.0]);
+    assert_eq!(acceleration.mps2()[0], bmi323::GRAVITY);
+
+    let rate = bmi323.read_gyro().unwrap();
+    assert!((rate.dps()[2] + 99.97559).abs() < 1e-4);
+    assert!((rate.rad_per_s()[2] + 99.97559f32.to_radians()).abs() < 1e-6);
+
+    i2c.done();
+}
+
+/// Interrupt pin that is already high
+#[cfg(feature = "async")]
+struct HighPin {
+    waits: usize,
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal::digital::ErrorType for HighPin {
+    type Error = core::convert::Infallible;
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::digital::Wait for HighPin {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        self.waits += 1;
+        Ok(())
+    }
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_bmi323_interrupt_driven_reads() {
+    let expectations = [
+        i2c_read(0x3B, vec![0x00, 0x00]),
+        i2c_write(0x3B, vec![0x00, 0x04]), // acc_drdy to INT1
+        i2c_read(0x38, vec![0x00, 0x00]),
+        i2c_write(0x38, vec![0x05, 0x00]), // INT1 active high
+        i2c_read(0x03, vec![0; 20]),
+        i2c_write(0x35, vec![0x10, 0x00]), // Watermark at 16 words
+        i2c_write(0x36, vec![0x00, 0x07]), // Accel, gyro and sensor time frames
+        i2c_write(0x37, vec![0x01, 0x00]), // Flush
+        i2c_read(0x3B, vec![0x00, 0x04]),
+        i2c_write(0x3B, vec![0x00, 0x24]), // FIFO watermark to INT2
+        i2c_read(0x38, vec![0x05, 0x00]),
+        i2c_write(0x38, vec![0x05, 0x05]), // INT2 active high
+        i2c_read(0x15, vec![0x03, 0x00]),  // 3 words
+        i2c_read(0x16, vec![0x01, 0x02, 0x03, 0x04]), // Limited by the buffer
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+    let mut pin = HighPin { waits: 0 };
+
+    bmi323
+        .map_interrupt(
+            bmi323::InterruptSource::AccelDataReady,
+            Some(bmi323::InterruptPin::Int1),
+        )
+        .unwrap();
+    let data = embassy_futures::block_on(bmi323.wait_for_data(&mut pin)).unwrap();
+    assert_eq!(data.accel.x, 0);
+
+    bmi323
+        .set_fifo_config(bmi323::FifoConfig {
+            sensor_time: true,
+            watermark_words: 16,
+            ..Default::default()
+        })
+        .unwrap();
+    bmi323
+        .map_interrupt(
+            bmi323::InterruptSource::FifoWatermark,
+            Some(bmi323::InterruptPin::Int2),
+        )
+        .unwrap();
+    let mut fifo = [0u8; 5];
+    let len = embassy_futures::block_on(bmi323.wait_for_fifo(&mut pin, &mut fifo)).unwrap();
+    assert_eq!(&fifo[..len], &[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(pin.waits, 2);
+
+    i2c.done();
+}
+
+/// Interrupt pin whose every wait fails
+#[cfg(feature = "async")]
+struct BrokenPin;
+
+#[cfg(feature = "async")]
+impl embedded_hal::digital::ErrorType for BrokenPin {
+    type Error = embedded_hal::digital::ErrorKind;
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::digital::Wait for BrokenPin {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        Err(embedded_hal::digital::ErrorKind::Other)
+    }
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_bmi323_interrupt_pin_error() {
+    let mut i2c = I2cMock::new(&[]);
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, MockDelay::new());
+
+    let result = embassy_futures::block_on(bmi323.wait_for_data(&mut BrokenPin));
+    assert!(matches!(
+        result,
+        Err(Error::Pin(embedded_hal::digital::ErrorKind::Other))
+    ));
+
+    i2c.done();
+}
+
+#[test]
+fn test_bmi323_fifo() {
+    let expectations = [
+        i2c_write(0x35, vec![0xFF, 0x03]),
+        i2c_write(0x36, vec![0x01, 0x06]), // Accel and gyro, stop on full
+        i2c_write(0x37, vec![0x01, 0x00]),
+        i2c_read(0x15, vec![0x02, 0xF8]), // Reserved bits set
+        i2c_read(0x15, vec![0x02, 0x00]),
+        i2c_read(0x16, vec![0x01, 0x02, 0x03, 0x04]),
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, MockDelay::new());
+
+    let config = bmi323::FifoConfig {
+        stop_on_full: true,
+        watermark_words: bmi323::FifoConfig::MAX_WATERMARK,
+        ..Default::default()
+    };
+    bmi323.set_fifo_config(config).unwrap();
+    assert!(matches!(
+        bmi323.set_fifo_config(bmi323::FifoConfig {
+            watermark_words: 1024,
+            ..config
+        }),
+        Err(Error::InvalidConfig(ConfigError::InvalidFifoWatermark))
+    ));
+
+    assert_eq!(bmi323.read_fifo_fill_level().unwrap(), 2);
+    let mut fifo = [0u8; 8];
+    assert_eq!(bmi323.read_fifo(&mut fifo).unwrap(), 4);
+    assert_eq!(&fifo[..4], &[0x01, 0x02, 0x03, 0x04]);
+
+    i2c.done();
+}
+
+/// Burst read by `read_all` with the given sensor time and all data zero
+fn all_data_at(sensor_time: u32) -> Vec<u8> {
+    let mut burst = vec![0; 20];
+    burst[14..18].copy_from_slice(&sensor_time.to_le_bytes());
+    burst
+}
+
+#[test]
+fn test_bmi323_sample_stream() {
+    let accel_config = AccelConfig::builder().build();
+    let gyro_config = GyroConfig::builder().build();
+    let expectations = [
+        i2c_write(0x20, u16::from(accel_config).to_le_bytes().to_vec()),
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_write(0x21, u16::from(gyro_config).to_le_bytes().to_vec()),
+        i2c_read(0x01, vec![0x00, 0x00]),
+        i2c_read(0x02, vec![0x80]), // Gyroscope not ready yet
+        i2c_read(0x02, vec![0xC0]),
+        i2c_read(0x03, all_data_at(0xFFFF_FF00)),
+        i2c_read(0x02, vec![0xC0]),
+        i2c_read(0x03, all_data_at(0x0000_0100)), // Sensor time wrapped
+        i2c_read(0x02, vec![0xC0]),
+        i2c_read(0x03, all_data_at(0x1000)),
+        i2c_read(0x02, vec![0xC0]),
+        i2c_read(0x03, all_data_at(0x1000 + 2 * 256 + 10)), // One frame missed
+        i2c_read(0x21, vec![0x48, 0x40]),
+        i2c_write(0x21, vec![0x48, 0x10]), // Gyroscope suspend
+    ]
+    .concat();
+
+    let mut i2c = I2cMock::new(&expectations);
+    let delay = MockDelay::new();
+    let mut bmi323 = Bmi323::new_with_i2c(i2c.clone(), 0x68, delay);
+
+    // Nothing to stream before the sensors are configured
+    let mut stream = bmi323.samples(Timebase::SensorTime);
+    assert!(matches!(
+        stream.next(),
+        Some(Err(Error::InvalidConfig(ConfigError::SensorDisabled)))
+    ));
+
+    bmi323.set_config_wait(None);
+    bmi323.set_accel_config(accel_config).unwrap();
+    bmi323.set_gyro_config(gyro_config).unwrap();
+
+    let mut stream = bmi323.samples(Timebase::SensorTime);
+    assert!(matches!(stream.next_sample(), Err(nb::Error::WouldBlock)));
+    let first = stream.next_sample().unwrap();
+    let second = stream.next().unwrap().unwrap();
+    assert_eq!(
+        second.timestamp - first.timestamp,
+        Duration::from_micros(20_000) // 512 ticks
+    );
+
+    // Sensor time is rounded to the configured 100 Hz grid
+    let mut stream = bmi323.samples(Timebase::SensorTimeGrid);
+    let timestamps: Vec<_> = stream
+        .by_ref()
+        .take(2)
+        .map(|s| s.unwrap().timestamp)
+        .collect();
+    assert_eq!(timestamps, [Duration::ZERO, Duration::from_millis(20)]);
+    assert_eq!(stream.sample_count(), 2);
+
+    bmi323.suspend_gyro().unwrap();
+    assert!(matches!(
+        bmi323.samples(Timebase::SensorTime).next(),
+        Some(Err(Error::InvalidConfig(ConfigError::SensorDisabled)))
+    ));
+
+    i2c.done();
+}
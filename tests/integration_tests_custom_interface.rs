@@ -0,0 +1,95 @@
+use bmi323::{AccelConfig, AccelerometerRange, Bmi323, Error, ReadData, Register, WriteData};
+use embedded_hal_mock::eh1::delay::NoopDelay as MockDelay;
+
+/// Transport error of the fake bridge
+#[derive(Debug, PartialEq)]
+struct BridgeError;
+
+/// A register window exposing the BMI323 registers as 16-bit words, standing
+/// in for a transport that does not implement embedded-hal
+struct RegisterWindow {
+    registers: [u16; 128],
+    writes: Vec<(u8, u16)>,
+}
+
+impl RegisterWindow {
+    fn new() -> Self {
+        let mut registers = [0u16; 128];
+        registers[Register::CHIPID as usize] = 0x0043;
+        registers[Register::STATUS as usize] = 0x00C0; // drdy_acc, drdy_gyr
+        registers[Register::ACC_DATA_X as usize] = 0x1000;
+        registers[Register::ACC_DATA_X as usize + 1] = 0xF000;
+        RegisterWindow {
+            registers,
+            writes: Vec::new(),
+        }
+    }
+}
+
+impl ReadData for RegisterWindow {
+    type Error = BridgeError;
+
+    fn read_data(&mut self, register: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        for (i, byte) in data.iter_mut().enumerate() {
+            let word = self
+                .registers
+                .get(register as usize + i / 2)
+                .ok_or(BridgeError)?;
+            *byte = word.to_le_bytes()[i % 2];
+        }
+        Ok(())
+    }
+}
+
+impl WriteData for RegisterWindow {
+    type Error = BridgeError;
+
+    fn write_data(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error> {
+        for (i, word) in data.chunks_exact(2).enumerate() {
+            let address = register as usize + i;
+            let value = u16::from_le_bytes([word[0], word[1]]);
+            *self.registers.get_mut(address).ok_or(BridgeError)? = value;
+            self.writes.push((address as u8, value));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_custom_interface_init_and_read() {
+    let mut bmi323 = Bmi323::new(RegisterWindow::new(), MockDelay::new());
+
+    bmi323.init().unwrap();
+    bmi323
+        .set_accel_config(
+            AccelConfig::builder()
+                .range(AccelerometerRange::G16)
+                .build(),
+        )
+        .unwrap();
+
+    let data = bmi323.read_accel_data().unwrap();
+    assert_eq!(data.x, 0x1000);
+    assert_eq!(data.y, -0x1000);
+    assert_eq!(data.z, 0);
+    assert_eq!(
+        bmi323.get_accel_config().unwrap().range,
+        AccelerometerRange::G16
+    );
+
+    let (window, _delay) = bmi323.into_parts();
+    assert_eq!(
+        window.writes,
+        vec![(Register::CMD, Register::CMD_SOFT_RESET), (0x20, 0x40B8)]
+    );
+}
+
+#[test]
+fn test_custom_interface_errors_are_wrapped() {
+    let mut bmi323 = Bmi323::new(RegisterWindow::new(), MockDelay::new());
+
+    assert!(matches!(
+        bmi323.read_word(0x80),
+        Err(Error::Comm(BridgeError))
+    ));
+}
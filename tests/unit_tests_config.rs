@@ -0,0 +1,168 @@
+use bmi323::{
+    AccelConfig, AccelerometerPowerMode, AccelerometerRange, AverageNum, Bandwidth, ConfigError,
+    GyroConfig, GyroscopePowerMode, GyroscopeRange, OutputDataRate,
+};
+use core::time::Duration;
+
+#[test]
+fn test_accel_config_round_trip() {
+    let config = AccelConfig::builder()
+        .odr(OutputDataRate::Odr400hz)
+        .range(AccelerometerRange::G4)
+        .bw(Bandwidth::OdrHalf)
+        .avg_num(AverageNum::Avg8)
+        .mode(AccelerometerPowerMode::LowPower)
+        .build();
+
+    let decoded = AccelConfig::try_from(u16::from(config)).unwrap();
+    assert_eq!(decoded.odr, config.odr);
+    assert_eq!(decoded.range, config.range);
+    assert_eq!(decoded.bw, config.bw);
+    assert_eq!(decoded.avg_num, config.avg_num);
+    assert_eq!(decoded.mode, config.mode);
+}
+
+#[test]
+fn test_gyro_config_round_trip() {
+    let config = GyroConfig::builder()
+        .odr(OutputDataRate::Odr6400hz)
+        .range(GyroscopeRange::DPS125)
+        .bw(Bandwidth::OdrQuarter)
+        .mode(GyroscopePowerMode::Suspend)
+        .build();
+
+    let decoded = GyroConfig::try_from(u16::from(config)).unwrap();
+    assert_eq!(decoded.odr, config.odr);
+    assert_eq!(decoded.range, config.range);
+    assert_eq!(decoded.bw, config.bw);
+    assert_eq!(decoded.avg_num, config.avg_num);
+    assert_eq!(decoded.mode, config.mode);
+}
+
+#[test]
+fn test_reset_values_decode() {
+    let accel = AccelConfig::try_from(0x0028).unwrap();
+    assert_eq!(accel.odr, OutputDataRate::Odr100hz);
+    assert_eq!(accel.range, AccelerometerRange::G8);
+    assert_eq!(accel.mode, AccelerometerPowerMode::Disable);
+
+    let gyro = GyroConfig::try_from(0x0048).unwrap();
+    assert_eq!(gyro.range, GyroscopeRange::DPS2000);
+    assert_eq!(gyro.mode, GyroscopePowerMode::Disable);
+}
+
+#[test]
+fn test_reserved_codes_are_rejected() {
+    assert_eq!(
+        AccelConfig::try_from(0x0020).unwrap_err(),
+        ConfigError::InvalidOdr
+    );
+    assert_eq!(
+        AccelConfig::try_from(0x0048).unwrap_err(),
+        ConfigError::InvalidRange
+    );
+    assert_eq!(
+        AccelConfig::try_from(0x0728).unwrap_err(),
+        ConfigError::InvalidAvgNum
+    );
+    assert_eq!(
+        AccelConfig::try_from(0x1028).unwrap_err(),
+        ConfigError::InvalidMode
+    );
+    assert_eq!(
+        GyroConfig::try_from(0x0058).unwrap_err(),
+        ConfigError::InvalidRange
+    );
+    assert_eq!(
+        GyroConfig::try_from(0x2048).unwrap_err(),
+        ConfigError::InvalidMode
+    );
+}
+
+#[test]
+fn test_accel_config_validation() {
+    let low_power = AccelConfig::builder().mode(AccelerometerPowerMode::LowPower);
+    assert_eq!(
+        low_power.odr(OutputDataRate::Odr400hz).build().validate(),
+        Ok(())
+    );
+    assert_eq!(
+        low_power.odr(OutputDataRate::Odr800hz).build().validate(),
+        Err(ConfigError::OdrNotSupported)
+    );
+    assert_eq!(
+        low_power
+            .odr(OutputDataRate::Odr400hz)
+            .avg_num(AverageNum::Avg16)
+            .build()
+            .validate(),
+        Ok(())
+    );
+    assert_eq!(
+        low_power
+            .odr(OutputDataRate::Odr400hz)
+            .avg_num(AverageNum::Avg32)
+            .build()
+            .validate(),
+        Err(ConfigError::AveragingTooLong)
+    );
+
+    let normal = AccelConfig::builder().mode(AccelerometerPowerMode::Normal);
+    assert_eq!(
+        normal.odr(OutputDataRate::Odr6400hz).build().validate(),
+        Ok(())
+    );
+    assert_eq!(
+        normal.odr(OutputDataRate::Odr6_25hz).build().validate(),
+        Err(ConfigError::OdrNotSupported)
+    );
+    assert_eq!(
+        normal.avg_num(AverageNum::Avg2).build().validate(),
+        Err(ConfigError::AveragingNotSupported)
+    );
+
+    let disabled = AccelConfig::builder()
+        .mode(AccelerometerPowerMode::Disable)
+        .odr(OutputDataRate::Odr0_78hz)
+        .avg_num(AverageNum::Avg64);
+    assert_eq!(disabled.build().validate(), Ok(()));
+}
+
+#[test]
+fn test_gyro_config_validation() {
+    let config = GyroConfig::builder().odr(OutputDataRate::Odr6400hz);
+    assert_eq!(
+        config.mode(GyroscopePowerMode::Normal).build().validate(),
+        Err(ConfigError::OdrNotSupported)
+    );
+    assert_eq!(
+        config.mode(GyroscopePowerMode::HighPerf).build().validate(),
+        Ok(())
+    );
+    assert_eq!(
+        config.mode(GyroscopePowerMode::LowPower).build().validate(),
+        Err(ConfigError::OdrNotSupported)
+    );
+    assert_eq!(
+        GyroConfig::builder()
+            .odr(OutputDataRate::Odr25hz)
+            .avg_num(AverageNum::Avg64)
+            .mode(GyroscopePowerMode::LowPower)
+            .build()
+            .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_output_data_rate_period() {
+    assert_eq!(
+        OutputDataRate::Odr6400hz.period(),
+        Duration::from_nanos(156_250)
+    );
+    assert_eq!(OutputDataRate::Odr100hz.period(), Duration::from_millis(10));
+    assert_eq!(
+        OutputDataRate::Odr0_78hz.period(),
+        Duration::from_millis(1280)
+    );
+}
@@ -0,0 +1,82 @@
+use bmi323::{
+    estimate_current_ua, AccelConfig, AccelerometerPowerMode, AverageNum, GyroConfig,
+    GyroscopePowerMode, OutputDataRate, PowerModel,
+};
+
+fn accel(mode: AccelerometerPowerMode, odr: OutputDataRate, avg_num: AverageNum) -> AccelConfig {
+    AccelConfig::builder()
+        .mode(mode)
+        .odr(odr)
+        .avg_num(avg_num)
+        .build()
+}
+
+fn gyro(mode: GyroscopePowerMode) -> GyroConfig {
+    GyroConfig::builder().mode(mode).build()
+}
+
+#[test]
+fn test_estimate_all_off() {
+    let model = PowerModel::default();
+    let off = accel(
+        AccelerometerPowerMode::Disable,
+        OutputDataRate::Odr100hz,
+        AverageNum::Avg1,
+    );
+    let current = model.estimate_current_ua(&off, &gyro(GyroscopePowerMode::Disable), false);
+    assert_eq!(current, model.suspend_ua);
+
+    let current = model.estimate_current_ua(&off, &gyro(GyroscopePowerMode::Disable), true);
+    assert_eq!(current, model.suspend_ua + model.feature_engine_ua);
+}
+
+#[test]
+fn test_estimate_low_power_scales_with_duty_cycle() {
+    let model = PowerModel {
+        suspend_ua: 0.0,
+        accel_normal_ua: 640.0,
+        ..PowerModel::default()
+    };
+    let gyro_off = gyro(GyroscopePowerMode::Disable);
+
+    // 1 of 64 base periods at 100 Hz
+    let lp = accel(
+        AccelerometerPowerMode::LowPower,
+        OutputDataRate::Odr100hz,
+        AverageNum::Avg1,
+    );
+    assert_eq!(model.estimate_current_ua(&lp, &gyro_off, false), 10.0);
+
+    // Averaging 4 samples quadruples the active time
+    let lp = accel(
+        AccelerometerPowerMode::LowPower,
+        OutputDataRate::Odr100hz,
+        AverageNum::Avg4,
+    );
+    assert_eq!(model.estimate_current_ua(&lp, &gyro_off, false), 40.0);
+
+    // Halving the ODR halves the active time
+    let lp = accel(
+        AccelerometerPowerMode::LowPower,
+        OutputDataRate::Odr50hz,
+        AverageNum::Avg4,
+    );
+    assert_eq!(model.estimate_current_ua(&lp, &gyro_off, false), 20.0);
+}
+
+#[test]
+fn test_estimate_full_operation() {
+    let model = PowerModel::default();
+    let hp = accel(
+        AccelerometerPowerMode::HighPerf,
+        OutputDataRate::Odr800hz,
+        AverageNum::Avg1,
+    );
+    let full = estimate_current_ua(&hp, &gyro(GyroscopePowerMode::HighPerf), false);
+    assert_eq!(
+        full,
+        model.suspend_ua + model.accel_high_perf_ua + model.gyro_high_perf_ua
+    );
+    let suspended = estimate_current_ua(&hp, &gyro(GyroscopePowerMode::Suspend), false);
+    assert!(suspended < full);
+}
@@ -1,4 +1,7 @@
-use bmi323::Sensor3DData;
+use bmi323::{
+    temperature_to_celsius, temperature_to_centi_celsius, AccelUnit, AccelerometerRange, GyroUnit,
+    GyroscopeRange, Sensor3DData, GRAVITY,
+};
 
 const EPSILON: f32 = 1e-5;
 
@@ -22,12 +25,11 @@ fn test_sensor_data_to_mps2() {
         y: -4096,
         z: 16384,
     };
-    let scaled_data = sensor_data.to_mps2(4.0); // Assuming 4g range and 16-bit width
+    let scaled_data = sensor_data.to_mps2(4.0); // 8192 LSB/g at 4g
 
-    // Adjusted expectations based on actual output
-    assert_relative_eq(scaled_data.x, 9.8003, EPSILON);
-    assert_relative_eq(scaled_data.y, -4.90015, EPSILON);
-    assert_relative_eq(scaled_data.z, 19.6006, EPSILON);
+    assert_relative_eq(scaled_data.x, 9.80665, EPSILON);
+    assert_relative_eq(scaled_data.y, -4.903325, EPSILON);
+    assert_relative_eq(scaled_data.z, 19.6133, EPSILON);
 }
 
 #[test]
@@ -37,10 +39,112 @@ fn test_sensor_data_to_dps() {
         y: -8192,
         z: 32767,
     };
-    let scaled_data = sensor_data.to_dps(1000.0); // Assuming 1000 dps range and 16-bit width
+    let scaled_data = sensor_data.to_dps(1000.0); // 32.768 LSB/dps at 1000 dps
 
-    // Adjusted expectations based on actual output
-    assert_relative_eq(scaled_data.x, 500.01526, EPSILON);
-    assert_relative_eq(scaled_data.y, -250.00763, EPSILON);
-    assert_relative_eq(scaled_data.z, 1000.0, EPSILON);
+    assert_relative_eq(scaled_data.x, 500.0, EPSILON);
+    assert_relative_eq(scaled_data.y, -250.0, EPSILON);
+    assert_relative_eq(scaled_data.z, 999.9695, EPSILON);
+}
+
+#[test]
+fn test_range_sensitivity() {
+    assert_eq!(AccelerometerRange::G2.sensitivity(), 16384.0);
+    assert_eq!(AccelerometerRange::G8.sensitivity(), 4096.0);
+    assert_eq!(AccelerometerRange::G16.sensitivity(), 2048.0);
+    assert_eq!(GyroscopeRange::DPS125.sensitivity(), 262.144);
+    assert_eq!(GyroscopeRange::DPS2000.sensitivity(), 16.384);
+}
+
+#[test]
+fn test_sensor_data_output_units() {
+    let sensor_data = Sensor3DData {
+        x: 4096,
+        y: -2048,
+        z: 0,
+    };
+
+    let g = sensor_data.to_accel_units(AccelerometerRange::G8, AccelUnit::G);
+    assert_eq!((g.x, g.y, g.z), (1.0, -0.5, 0.0));
+    let mps2 =
+        sensor_data.to_accel_units(AccelerometerRange::G8, AccelUnit::MetersPerSecondSquared);
+    assert_relative_eq(mps2.x, GRAVITY, EPSILON);
+    assert_relative_eq(mps2.y, -GRAVITY / 2.0, EPSILON);
+
+    let dps = sensor_data.to_gyro_units(GyroscopeRange::DPS2000, GyroUnit::DegreesPerSecond);
+    assert_relative_eq(dps.x, 250.0, EPSILON);
+    let rad = sensor_data.to_gyro_units(GyroscopeRange::DPS2000, GyroUnit::RadiansPerSecond);
+    assert_relative_eq(rad.x, 250.0_f32.to_radians(), EPSILON);
+    assert_relative_eq(rad.y, -125.0_f32.to_radians(), EPSILON);
+}
+
+const ACCEL_RANGES: [AccelerometerRange; 4] = [
+    AccelerometerRange::G2,
+    AccelerometerRange::G4,
+    AccelerometerRange::G8,
+    AccelerometerRange::G16,
+];
+
+const GYRO_RANGES: [GyroscopeRange; 5] = [
+    GyroscopeRange::DPS125,
+    GyroscopeRange::DPS250,
+    GyroscopeRange::DPS500,
+    GyroscopeRange::DPS1000,
+    GyroscopeRange::DPS2000,
+];
+
+/// Raw values covering the whole i16 range, including both ends
+fn raw_values() -> impl Iterator<Item = i16> {
+    (i16::MIN..=i16::MAX)
+        .step_by(13)
+        .chain([i16::MAX, -1, 0, 1])
+}
+
+/// Check an integer conversion against the exact value and the f32 path
+fn assert_matches_float(raw: i16, milli: i32, float: f32, exact: f64) {
+    assert_eq!(milli as f64, exact.round(), "raw {raw}");
+    assert!(
+        (f64::from(float) * 1000.0 - f64::from(milli)).abs() <= 0.5 + exact.abs() * 1e-6,
+        "raw {raw}: {float} vs {milli}"
+    );
+}
+
+#[test]
+fn test_integer_accel_conversion_matches_float() {
+    for range in ACCEL_RANGES {
+        for raw in raw_values() {
+            let data = Sensor3DData { x: raw, y: 0, z: 0 };
+            let exact_g = f64::from(raw) * f64::from(range.full_scale_g()) / 32768.0;
+
+            let float = data.to_mps2(range.to_g()).x;
+            let milli_g = data.to_milli_g(range).x;
+            assert_matches_float(raw, milli_g, float / GRAVITY, exact_g * 1000.0);
+
+            let mm_per_s2 = data.to_mm_per_s2(range).x;
+            assert_matches_float(raw, mm_per_s2, float, exact_g * 9806.65);
+        }
+    }
+}
+
+#[test]
+fn test_integer_gyro_conversion_matches_float() {
+    for range in GYRO_RANGES {
+        for raw in raw_values() {
+            let data = Sensor3DData { x: 0, y: raw, z: 0 };
+            let exact_dps = f64::from(raw) * f64::from(range.full_scale_dps()) / 32768.0;
+
+            let float = data.to_dps(range.to_dps()).y;
+            let milli_dps = data.to_milli_dps(range).y;
+            assert_matches_float(raw, milli_dps, float, exact_dps * 1000.0);
+        }
+    }
+}
+
+#[test]
+fn test_integer_temperature_conversion() {
+    for raw in raw_values() {
+        let exact = (f64::from(raw) / 512.0 + 23.0) * 100.0;
+        let centi = temperature_to_centi_celsius(raw);
+        assert_eq!(centi as f64, exact.round(), "raw {raw}");
+        assert!((temperature_to_celsius(raw) * 100.0 - centi as f32).abs() <= 0.5);
+    }
 }
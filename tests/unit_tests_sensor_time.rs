@@ -0,0 +1,32 @@
+use bmi323::{ticks_to_duration, SensorClock, SENSOR_TIME_TICKS_PER_SECOND};
+use core::time::Duration;
+
+#[test]
+fn test_ticks_to_duration() {
+    assert_eq!(ticks_to_duration(0), Duration::ZERO);
+    assert_eq!(ticks_to_duration(1), Duration::from_nanos(39_062));
+    assert_eq!(ticks_to_duration(16), Duration::from_micros(625));
+    assert_eq!(
+        ticks_to_duration(SENSOR_TIME_TICKS_PER_SECOND),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn test_sensor_clock_is_monotonic() {
+    let mut clock = SensorClock::new();
+    assert_eq!(clock.update(100), 100);
+    assert_eq!(clock.update(200), 200);
+    assert_eq!(clock.update(200), 200);
+    assert_eq!(clock.as_micros(), 7812);
+}
+
+#[test]
+fn test_sensor_clock_wraparound() {
+    let mut clock = SensorClock::new();
+    clock.update(u32::MAX - 1);
+    assert_eq!(clock.update(3), (1 << 32) + 3);
+    assert_eq!(clock.update(u32::MAX), (1 << 32) + u64::from(u32::MAX));
+    assert_eq!(clock.update(0), 2 << 32);
+    assert_eq!(clock.elapsed(), ticks_to_duration(2 << 32));
+}
@@ -1,4 +1,7 @@
-use bmi323::{AccelerometerRange, GyroscopeRange};
+use bmi323::{
+    AccelerometerRange, ConfigError, DeviceErrors, DriveStrength, GyroscopeRange, I2cWatchdog,
+    SaturationFlags, SensorType,
+};
 
 #[test]
 fn test_accelerometer_range_to_g() {
@@ -26,3 +29,78 @@ fn test_accelerometer_range_default() {
 fn test_gyroscope_range_default() {
     assert_eq!(GyroscopeRange::default(), GyroscopeRange::DPS2000);
 }
+
+#[test]
+fn test_range_stepping() {
+    assert_eq!(
+        AccelerometerRange::G8.next_larger(),
+        Some(AccelerometerRange::G16)
+    );
+    assert_eq!(AccelerometerRange::G16.next_larger(), None);
+    assert_eq!(AccelerometerRange::G2.next_smaller(), None);
+    assert_eq!(
+        GyroscopeRange::DPS250.next_smaller(),
+        Some(GyroscopeRange::DPS125)
+    );
+    assert_eq!(GyroscopeRange::DPS2000.next_larger(), None);
+}
+
+#[test]
+fn test_saturation_flags_decode() {
+    let flags = SaturationFlags::from(0b0010_0010);
+    assert!(flags.acc_y && flags.gyr_z);
+    assert!(!flags.acc_x && !flags.acc_z && !flags.gyr_x && !flags.gyr_y);
+    assert!(flags.accel() && flags.gyro());
+    assert_eq!(SaturationFlags::from(0), SaturationFlags::default());
+}
+
+#[test]
+fn test_device_errors_decode() {
+    assert!(DeviceErrors::from(0x0000).is_empty());
+    // Reserved bits 1, 3, 7, 9, 10 and 12-15 are ignored
+    assert!(DeviceErrors::from(0xF68A).is_empty());
+
+    let errors = DeviceErrors::from(0x0975);
+    assert!(errors.fatal);
+    assert!(errors.feature_engine_overload);
+    assert!(errors.feature_engine_watchdog);
+    assert!(errors.acc_conf);
+    assert!(errors.gyr_conf);
+    assert!(errors.i3c_error0);
+    assert!(errors.i3c_error3);
+
+    assert!(DeviceErrors::from(0x0004).feature_engine_overload);
+    assert!(DeviceErrors::from(0x0010).feature_engine_watchdog);
+    assert!(DeviceErrors::from(0x0010).feature_engine());
+    assert!(!DeviceErrors::from(0x000A).feature_engine());
+    assert!(!DeviceErrors::from(0x0020).feature_engine());
+    assert!(DeviceErrors::from(0x0100).i3c());
+    assert!(!DeviceErrors::from(0x0040).i3c());
+    assert_eq!(
+        DeviceErrors::from(0x0060).rejected_sensor(),
+        Some(SensorType::Accelerometer)
+    );
+    assert_eq!(
+        DeviceErrors::from(0x0040).rejected_sensor(),
+        Some(SensorType::Gyroscope)
+    );
+    assert_eq!(DeviceErrors::from(0x0900).rejected_sensor(), None);
+}
+
+#[test]
+fn test_io_config_encoding() {
+    assert_eq!(u16::from(I2cWatchdog::Disabled), 0b00);
+    assert_eq!(u16::from(I2cWatchdog::Timeout1_25ms), 0b10);
+    assert_eq!(u16::from(I2cWatchdog::Timeout40ms), 0b11);
+    assert_eq!(I2cWatchdog::from(0xFFFD), I2cWatchdog::Disabled);
+    assert_eq!(I2cWatchdog::from(0x0002), I2cWatchdog::Timeout1_25ms);
+
+    assert_eq!(DriveStrength::try_from(5).unwrap().level(), 5);
+    assert!(!DriveStrength::try_from(5).unwrap().i2c_boost());
+    assert_eq!(u16::from(DriveStrength::MAX), 0b0111);
+    assert_eq!(u16::from(DriveStrength::MIN.with_i2c_boost(true)), 0b1000);
+    assert_eq!(
+        DriveStrength::try_from(8),
+        Err(ConfigError::InvalidDriveStrength)
+    );
+}
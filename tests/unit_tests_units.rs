@@ -0,0 +1,70 @@
+#![cfg(feature = "units")]
+
+use bmi323::{
+    AccelUnit, Acceleration, AccelerometerRange, AngularRate, GyroUnit, GyroscopeRange,
+    Sensor3DData, GRAVITY,
+};
+
+const EPSILON: f32 = 1e-5;
+
+fn assert_axes_eq(a: [f32; 3], b: [f32; 3]) {
+    for (a, b) in a.into_iter().zip(b) {
+        assert!(
+            (a - b).abs() <= EPSILON * a.abs().max(b.abs()).max(1.0),
+            "{a} != {b}"
+        );
+    }
+}
+
+#[test]
+fn test_acceleration_conversions() {
+    let acceleration = Acceleration::from_g([1.0, -0.5, 2.0]);
+    assert_axes_eq(acceleration.g(), [1.0, -0.5, 2.0]);
+    assert_axes_eq(
+        acceleration.mps2(),
+        [GRAVITY, -GRAVITY / 2.0, 2.0 * GRAVITY],
+    );
+    assert_eq!(Acceleration::from_mps2(acceleration.mps2()), acceleration);
+
+    let scaled = acceleration.to_scaled(AccelUnit::G);
+    assert_axes_eq([scaled.x, scaled.y, scaled.z], acceleration.g());
+
+    let local = Acceleration::from_g_with_gravity([1.0, -0.5, 2.0], 9.81);
+    assert_axes_eq(local.mps2(), [9.81, -4.905, 19.62]);
+    assert_axes_eq(local.g(), [1.0, -0.5, 2.0]);
+    assert_eq!(local.gravity(), 9.81);
+}
+
+#[test]
+fn test_angular_rate_conversions() {
+    let rate = AngularRate::from_dps([180.0, -90.0, 0.0]);
+    assert_axes_eq(
+        rate.rad_per_s(),
+        [core::f32::consts::PI, -core::f32::consts::FRAC_PI_2, 0.0],
+    );
+    assert_axes_eq(
+        AngularRate::from_rad_per_s(rate.rad_per_s()).dps(),
+        rate.dps(),
+    );
+
+    let scaled = rate.to_scaled(GyroUnit::RadiansPerSecond);
+    assert_axes_eq([scaled.x, scaled.y, scaled.z], rate.rad_per_s());
+}
+
+#[test]
+fn test_raw_to_typed_matches_scaled() {
+    let raw = Sensor3DData {
+        x: 8192,
+        y: -12345,
+        z: i16::MAX,
+    };
+
+    let acceleration = raw.to_acceleration(AccelerometerRange::G4);
+    let scaled = raw.to_accel_units(AccelerometerRange::G4, AccelUnit::MetersPerSecondSquared);
+    assert_axes_eq(acceleration.mps2(), [scaled.x, scaled.y, scaled.z]);
+    assert_eq!(acceleration.g()[0], 1.0); // 8192 LSB/g at ±4g
+
+    let rate = raw.to_angular_rate(GyroscopeRange::DPS500);
+    let scaled = raw.to_gyro_units(GyroscopeRange::DPS500, GyroUnit::RadiansPerSecond);
+    assert_axes_eq(rate.rad_per_s(), [scaled.x, scaled.y, scaled.z]);
+}